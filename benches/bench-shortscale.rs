@@ -1,75 +1,147 @@
-use bencher::{benchmark_group, benchmark_main, black_box, Bencher};
+use criterion::measurement::WallTime;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
+#[allow(clippy::single_component_path_imports)]
 use shortscale;
-
-#[cfg(extra)]
+use shortscale::locale::Locale;
 use std::fmt::Write;
 
 const NUM: u64 = 740_991_777;
 
-fn a_shortscale(b: &mut Bencher) {
-    let mut cnt: u64 = 0;
-    let mut bytes: usize = 0;
-    b.iter(|| {
-        let new_string = shortscale::shortscale(black_box(NUM));
-        cnt += 1;
-        bytes += new_string.len();
+fn a_shortscale(c: &mut Criterion) {
+    c.bench_function("a_shortscale", |b| {
+        b.iter(|| shortscale::shortscale(black_box(NUM)))
+    });
+}
+
+fn b_shortscale_string_writer_no_alloc(c: &mut Criterion) {
+    let mut buf = String::with_capacity(shortscale::MAX_LEN);
+    c.bench_function("b_shortscale_string_writer_no_alloc", |b| {
+        b.iter(|| {
+            buf.clear();
+            shortscale::shortscale_string_writer(&mut buf, black_box(NUM));
+        })
+    });
+}
+
+fn h_num_words_no_alloc(c: &mut Criterion) {
+    let mut buf = String::with_capacity(shortscale::MAX_LEN);
+    c.bench_function("h_num_words_no_alloc", |b| {
+        b.iter(|| {
+            buf.clear();
+            let words = shortscale::NumWords::new(black_box(NUM));
+            write!(&mut buf, "{}", words).unwrap();
+        })
     });
-    // println!("a_shortscale {} iterations, {} bytes", cnt, bytes);
 }
 
-fn b_shortscale_string_writer_no_alloc(b: &mut Bencher) {
-    let mut buf = String::with_capacity(238);
-    let mut cnt: u64 = 0;
-    let mut bytes: usize = 0;
-    b.iter(|| {
-        buf.clear();
-        shortscale::shortscale_string_writer(&mut buf, black_box(NUM));
-        cnt += 1;
-        bytes += buf.len();
+fn i_shortscale_ref_no_alloc(c: &mut Criterion) {
+    c.bench_function("i_shortscale_ref_no_alloc", |b| {
+        b.iter(|| shortscale::shortscale_ref(black_box(NUM)).len())
+    });
+}
+
+// word by word writer used for other Options and locales
+fn j_shortscale_locale_no_alloc(c: &mut Criterion) {
+    let mut group = c.benchmark_group("j_shortscale_locale_no_alloc");
+    bench_locale(&mut group, "en_gb", &shortscale::locale::EnGb);
+    bench_locale(&mut group, "en_us", &shortscale::locale::EnUs);
+    bench_locale(&mut group, "en_in", &shortscale::locale::EnIn);
+    #[cfg(feature = "de")]
+    bench_locale(&mut group, "de", &shortscale::locale::De);
+    #[cfg(feature = "fr")]
+    bench_locale(&mut group, "fr", &shortscale::locale::Fr);
+    #[cfg(feature = "es")]
+    bench_locale(&mut group, "es", &shortscale::locale::Es::default());
+    #[cfg(feature = "ja")]
+    bench_locale(&mut group, "ja", &shortscale::locale::Ja);
+    group.finish();
+}
+
+fn bench_locale<L: Locale>(group: &mut BenchmarkGroup<WallTime>, name: &str, locale: &L) {
+    let mut buf = String::with_capacity(shortscale::MAX_LEN);
+    let options = shortscale::Options::new();
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            buf.clear();
+            shortscale::shortscale_locale_write(&mut buf, black_box(NUM), locale, &options)
+                .unwrap();
+        })
+    });
+}
+
+// numbers of all magnitudes, to avoid measuring one path through the table
+fn k_shortscale_string_writer_mixed(c: &mut Criterion) {
+    let nums: Vec<u64> = (0..64).map(|i| 0x2545_f491_4f6c_dd1d_u64 >> i).collect();
+    let mut buf = String::with_capacity(shortscale::MAX_LEN);
+    c.bench_function("k_shortscale_string_writer_mixed", |b| {
+        b.iter(|| {
+            for num in nums.iter() {
+                buf.clear();
+                shortscale::shortscale_string_writer(&mut buf, black_box(*num));
+            }
+        })
+    });
+}
+
+fn l_shortscale_ordinal_no_alloc(c: &mut Criterion) {
+    let mut buf = String::with_capacity(shortscale::MAX_LEN);
+    c.bench_function("l_shortscale_ordinal_no_alloc", |b| {
+        b.iter(|| {
+            buf.clear();
+            shortscale::shortscale_ordinal_string_writer(&mut buf, black_box(NUM));
+        })
+    });
+}
+
+fn m_shortscale_parse(c: &mut Criterion) {
+    let words = shortscale::shortscale(NUM);
+    c.bench_function("m_shortscale_parse", |b| {
+        b.iter(|| shortscale::shortscale_parse(black_box(&words)).unwrap())
     });
-    // println!("b_shortscale_string_writer_no_alloc {} iterations, {} bytes", cnt, bytes);
 }
 
 #[cfg(extra)]
-fn e_display_no_alloc(b: &mut Bencher) {
-    let mut buf = String::with_capacity(238);
-    b.iter(|| {
-        buf.clear();
-        let words = shortscale::extra::NumWords::new(black_box(NUM));
-        write!(&mut buf, "{}", words).unwrap();
+fn e_display_no_alloc(c: &mut Criterion) {
+    let mut buf = String::with_capacity(shortscale::MAX_LEN);
+    c.bench_function("e_display_no_alloc", |b| {
+        b.iter(|| {
+            buf.clear();
+            let words = shortscale::extra::NumWords::new(black_box(NUM));
+            write!(&mut buf, "{}", words).unwrap();
+        })
     });
 }
 
 #[cfg(extra)]
-fn c_str_push(b: &mut Bencher) {
-    b.iter(|| {
-        shortscale::extra::shortscale_str_push(black_box(NUM));
+fn c_str_push(c: &mut Criterion) {
+    c.bench_function("c_str_push", |b| {
+        b.iter(|| shortscale::extra::shortscale_str_push(black_box(NUM)))
     });
 }
 
 #[cfg(extra)]
-fn d_vec_push(b: &mut Bencher) {
-    b.iter(|| {
-        shortscale::extra::shortscale_vec_push(black_box(NUM));
+fn d_vec_push(c: &mut Criterion) {
+    c.bench_function("d_vec_push", |b| {
+        b.iter(|| shortscale::extra::shortscale_vec_push(black_box(NUM)))
     });
 }
 
 #[cfg(extra)]
-fn f_vec_concat(b: &mut Bencher) {
-    b.iter(|| {
-        shortscale::extra::shortscale_vec_concat(black_box(NUM));
+fn f_vec_concat(c: &mut Criterion) {
+    c.bench_function("f_vec_concat", |b| {
+        b.iter(|| shortscale::extra::shortscale_vec_concat(black_box(NUM)))
     });
 }
 
 #[cfg(extra)]
-fn g_string_join(b: &mut Bencher) {
-    b.iter(|| {
-        shortscale::extra::shortscale_string_join(black_box(NUM));
+fn g_string_join(c: &mut Criterion) {
+    c.bench_function("g_string_join", |b| {
+        b.iter(|| shortscale::extra::shortscale_string_join(black_box(NUM)))
     });
 }
 
 #[cfg(extra)]
-benchmark_group!(
+criterion_group!(
     benches,
     a_shortscale,
     b_shortscale_string_writer_no_alloc,
@@ -78,58 +150,25 @@ benchmark_group!(
     e_display_no_alloc,
     f_vec_concat,
     g_string_join,
+    h_num_words_no_alloc,
+    i_shortscale_ref_no_alloc,
+    j_shortscale_locale_no_alloc,
+    k_shortscale_string_writer_mixed,
+    l_shortscale_ordinal_no_alloc,
+    m_shortscale_parse,
 );
 
 #[cfg(not(extra))]
-benchmark_group!(benches, a_shortscale, b_shortscale_string_writer_no_alloc);
-
-benchmark_main!(benches);
-
-/*
-// writer code for experimental performance evaluation
-
-for word in WORDS.iter() {
-    f.write_str(word)?;
-    f.write_str(" ")?;
-};
-Ok(())
-
-#[allow(dead_code)]
-const WORDS: [&str; 35] = [
-    "nine",
-    "hundred",
-    "and",
-    "ninety",
-    "nine",
-    "quadrillion",
-    "nine",
-    "hundred",
-    "and",
-    "ninety",
-    "nine",
-    "trillion",
-    "nine",
-    "hundred",
-    "and",
-    "ninety",
-    "nine",
-    "billion",
-    "nine",
-    "hundred",
-    "and",
-    "ninety",
-    "nine",
-    "million",
-    "nine",
-    "hundred",
-    "and",
-    "ninety",
-    "nine",
-    "thousand",
-    "nine",
-    "hundred",
-    "and",
-    "ninety",
-    "nine",
-];
-*/
+criterion_group!(
+    benches,
+    a_shortscale,
+    b_shortscale_string_writer_no_alloc,
+    h_num_words_no_alloc,
+    i_shortscale_ref_no_alloc,
+    j_shortscale_locale_no_alloc,
+    k_shortscale_string_writer_mixed,
+    l_shortscale_ordinal_no_alloc,
+    m_shortscale_parse,
+);
+
+criterion_main!(benches);
@@ -0,0 +1,12 @@
+// cargo +nightly fuzz run parse
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shortscale::{shortscale, shortscale_parse};
+
+fuzz_target!(|words: &str| {
+    // any number which parses must format back to the same number
+    if let Ok(num) = shortscale_parse(words) {
+        assert_eq!(shortscale_parse(&shortscale(num)), Ok(num));
+    }
+});
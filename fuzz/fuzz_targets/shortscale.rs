@@ -0,0 +1,16 @@
+// cargo +nightly fuzz run shortscale
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shortscale::{shortscale, shortscale_len, shortscale_parse, MAX_LEN};
+
+fuzz_target!(|num: u64| {
+    let words = shortscale(num);
+    assert!(!words.contains("  "));
+    assert!(!words.starts_with("and ") && !words.ends_with(" and"));
+    assert_eq!(shortscale_len(num), words.len());
+    assert!(words.len() <= MAX_LEN);
+    if words != "(big number)" {
+        assert_eq!(shortscale_parse(&words), Ok(num));
+    }
+});
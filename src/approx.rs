@@ -0,0 +1,169 @@
+//! Approximate numbers, rounded to significant digits.
+
+use crate::locale::WordWriter;
+use crate::{shortscale_write_with, Case, Options, MAX};
+use std::convert::TryFrom;
+
+/// Rounding mode for [ApproxOptions](./struct.ApproxOptions.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+    /// Round half up, e.g. 450 to "about five hundred".
+    #[default]
+    Nearest,
+    /// Round towards zero, e.g. 499 to "over four hundred".
+    Down,
+    /// Round away from zero, e.g. 401 to "under five hundred".
+    Up,
+}
+
+/// Word before a rounded number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Prefix {
+    /// "about", "over", or "under" depending on the Rounding, nothing if the number is exact.
+    #[default]
+    Auto,
+    /// The same word for every rounded number, nothing if the number is exact.
+    Word(&'static str),
+    /// No prefix.
+    None,
+}
+
+/// Options for [shortscale_approx_with](./fn.shortscale_approx_with.html).
+///
+/// The default is 2 significant digits, rounded to the nearest, with an automatic prefix.
+///
+/// # Example
+/// ```
+/// use shortscale::{ApproxOptions, Prefix, Rounding};
+///
+/// let options = ApproxOptions::new()
+///     .significant(1)
+///     .rounding(Rounding::Up)
+///     .prefix(Prefix::Word("almost"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApproxOptions {
+    pub significant: u8,
+    pub rounding: Rounding,
+    pub prefix: Prefix,
+    pub options: Options,
+}
+
+impl Default for ApproxOptions {
+    fn default() -> Self {
+        Self {
+            significant: 2,
+            rounding: Rounding::default(),
+            prefix: Prefix::default(),
+            options: Options::new(),
+        }
+    }
+}
+
+impl ApproxOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of significant digits, at least 1.
+    pub fn significant(mut self, significant: u8) -> Self {
+        self.significant = significant;
+        self
+    }
+
+    /// Set the rounding mode.
+    pub fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Set the prefix word.
+    pub fn prefix(mut self, prefix: Prefix) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    /// Set the [Options](./struct.Options.html) for the words.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// Returns words for a number rounded to significant digits, e.g. "about four hundred and twenty billion".
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_approx;
+///
+/// assert_eq!(
+///     shortscale_approx(420_012_345_678, 2),
+///     "about four hundred and twenty billion"
+/// );
+/// assert_eq!(shortscale_approx(999_600, 2), "about one million");
+/// assert_eq!(shortscale_approx(420, 2), "four hundred and twenty");
+/// ```
+pub fn shortscale_approx(num: u64, significant: u8) -> String {
+    shortscale_approx_with(num, &ApproxOptions::new().significant(significant))
+}
+
+/// Same as shortscale_approx but with [ApproxOptions](./struct.ApproxOptions.html).
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_approx_with, ApproxOptions, Rounding};
+///
+/// let options = ApproxOptions::new().significant(1).rounding(Rounding::Up);
+/// assert_eq!(shortscale_approx_with(960_000, &options), "under one million");
+/// ```
+pub fn shortscale_approx_with(num: u64, approx: &ApproxOptions) -> String {
+    let significant = approx.significant.max(1);
+    let (rounded, rounding) = match round(num, significant, approx.rounding) {
+        _ if num > MAX => (num, approx.rounding),
+        // round down instead of rounding up past the largest number with words
+        rounded if rounded > MAX => (round(num, significant, Rounding::Down), Rounding::Down),
+        rounded => (rounded, approx.rounding),
+    };
+    let prefix = match (approx.prefix, rounding) {
+        _ if rounded == num => None,
+        (Prefix::None, _) => None,
+        (Prefix::Word(word), _) => Some(word),
+        (Prefix::Auto, Rounding::Nearest) => Some("about"),
+        (Prefix::Auto, Rounding::Down) => Some("over"),
+        (Prefix::Auto, Rounding::Up) => Some("under"),
+    };
+
+    let mut s = String::new();
+    let mut options = approx.options;
+    if let Some(prefix) = prefix {
+        // writing into a String never fails
+        WordWriter::new(&mut s, &options).word(prefix).unwrap();
+        s.push_str(options.separator);
+        if options.case == Case::Sentence {
+            options = options.case(Case::Lower);
+        }
+    }
+    shortscale_write_with(&mut s, rounded, &options).unwrap();
+    s
+}
+
+// rounds to significant digits, larger than u64::MAX becomes u64::MAX
+fn round(num: u64, significant: u8, rounding: Rounding) -> u64 {
+    let digits = match num.checked_ilog10() {
+        Some(log) => log + 1,
+        None => return num,
+    };
+    let significant = u32::from(significant);
+    if digits <= significant {
+        return num;
+    }
+    let factor = 10_u128.pow(digits - significant);
+    let n = u128::from(num);
+    let rounded = match rounding {
+        Rounding::Nearest => (n + factor / 2) / factor * factor,
+        Rounding::Down => n / factor * factor,
+        Rounding::Up => n.div_ceil(factor) * factor,
+    };
+    u64::try_from(rounded).unwrap_or(u64::MAX)
+}
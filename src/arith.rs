@@ -0,0 +1,99 @@
+//! Checked arithmetic on NumWords, e.g. sums and percentages.
+
+use crate::{shortscale_fraction, shortscale_string_writer, Error, NumWords, MAX};
+use std::convert::TryFrom;
+
+impl NumWords {
+    /// Returns NumWords for the sum of numbers,
+    /// or an error if the sum is larger than 999_999_999_999_999_999.
+    ///
+    /// # Example
+    /// ```
+    /// use shortscale::NumWords;
+    ///
+    /// let total = NumWords::sum(&[400, 20]).unwrap();
+    /// assert_eq!(total.to_string(), "four hundred and twenty");
+    /// assert!(NumWords::sum(&[999_999_999_999_999_999, 1]).is_err());
+    /// ```
+    pub fn sum(nums: &[u64]) -> Result<NumWords, Error> {
+        nums.iter()
+            .try_fold(0_u64, |sum, num| sum.checked_add(*num))
+            .filter(|sum| *sum <= MAX)
+            .map(NumWords::new)
+            .ok_or_else(Error::out_of_range)
+    }
+
+    /// Returns NumWords for the product of numbers,
+    /// or an error if the product is larger than 999_999_999_999_999_999.
+    ///
+    /// The product of no numbers is one.
+    pub fn product(nums: &[u64]) -> Result<NumWords, Error> {
+        nums.iter()
+            .try_fold(1_u64, |product, num| product.checked_mul(*num))
+            .filter(|product| *product <= MAX)
+            .map(NumWords::new)
+            .ok_or_else(Error::out_of_range)
+    }
+
+    /// Returns words for part of a whole in percent, rounded to the nearest percent,
+    /// e.g. "seventy five percent".
+    ///
+    /// Returns an error if the whole is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use shortscale::NumWords;
+    ///
+    /// assert_eq!(NumWords::percent(3, 4).unwrap(), "seventy five percent");
+    /// assert_eq!(NumWords::percent(2, 3).unwrap(), "sixty seven percent");
+    /// ```
+    pub fn percent(part: u64, whole: u64) -> Result<String, Error> {
+        let whole = check_whole(whole)?;
+        let percent = (u128::from(part) * 200 + whole) / (whole * 2);
+        let percent = match u64::try_from(percent) {
+            Ok(percent) if percent <= MAX => percent,
+            _ => return Err(Error::out_of_range()),
+        };
+        let mut s = String::new();
+        shortscale_string_writer(&mut s, percent);
+        s.push_str(" percent");
+        Ok(s)
+    }
+
+    /// Returns words for part of a whole as a fraction in lowest terms,
+    /// e.g. "three quarters", see [shortscale_fraction](./fn.shortscale_fraction.html).
+    ///
+    /// Returns an error if the whole is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use shortscale::NumWords;
+    ///
+    /// assert_eq!(NumWords::fraction(75, 100).unwrap(), "three quarters");
+    /// assert_eq!(NumWords::fraction(6, 3).unwrap(), "two");
+    /// ```
+    pub fn fraction(part: u64, whole: u64) -> Result<String, Error> {
+        check_whole(whole)?;
+        let divisor = gcd(part, whole);
+        Ok(shortscale_fraction(part / divisor, whole / divisor))
+    }
+}
+
+fn check_whole(whole: u64) -> Result<u128, Error> {
+    match whole {
+        0 => Err(Error::OutOfRange {
+            min: 1,
+            max: u64::MAX,
+        }),
+        _ => Ok(u128::from(whole)),
+    }
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+    a
+}
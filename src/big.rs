@@ -0,0 +1,144 @@
+//! Numbers larger than u64, given as strings of decimal digits.
+
+use crate::locale::{write_english_hundreds, WordWriter};
+use crate::{map, map_with, Error, Options, Style};
+use std::fmt;
+
+// prefixes for million to nonillion
+const SMALL: [&str; 10] = [
+    "", "m", "b", "tr", "quadr", "quint", "sext", "sept", "oct", "non",
+];
+
+// prefixes for the units and tens of decillion to novemnonagintillion
+const UNITS: [&str; 10] = [
+    "", "un", "duo", "tre", "quattuor", "quin", "sex", "septen", "octo", "novem",
+];
+const TENS: [&str; 10] = [
+    "",
+    "dec",
+    "vigint",
+    "trigint",
+    "quadragint",
+    "quinquagint",
+    "sexagint",
+    "septuagint",
+    "octogint",
+    "nonagint",
+];
+
+// largest scale with a name, centillion is 1000^101
+const MAX_SCALE: usize = 101;
+
+/// Returns String with words given a string of decimal digits of any length.
+///
+/// Scale words continue after quadrillion with quintillion, sextillion, ...
+/// up to centillion (10^303). Larger scales are written as "times ten to the power of".  
+/// Leading zeros are ignored, and characters other than ASCII digits return an error.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_bigint;
+///
+/// assert_eq!(
+///     shortscale_bigint("18446744073709551616").unwrap(),
+///     "eighteen quintillion four hundred and forty six quadrillion \
+///     seven hundred and forty four trillion seventy three billion \
+///     seven hundred and nine million five hundred and fifty one thousand \
+///     six hundred and sixteen"
+/// );
+/// assert_eq!(shortscale_bigint("1000000000000000000000000000000000").unwrap(), "one decillion");
+/// ```
+pub fn shortscale_bigint(digits: &str) -> Result<String, Error> {
+    shortscale_bigint_with(digits, &Options::new())
+}
+
+/// Same as shortscale_bigint but with [Options](./struct.Options.html).
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_bigint_with, Options, Style};
+///
+/// let options = Options::new().style(Style::US);
+/// assert_eq!(
+///     shortscale_bigint_with("1000000000000000000101", &options).unwrap(),
+///     "one sextillion one hundred one"
+/// );
+/// ```
+pub fn shortscale_bigint_with(digits: &str, options: &Options) -> Result<String, Error> {
+    if digits.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    if let Some((offset, ch)) = digits.char_indices().find(|(_, ch)| !ch.is_ascii_digit()) {
+        return Err(Error::InvalidChar { ch, offset });
+    }
+    let digits = digits.trim_start_matches('0').as_bytes();
+    let count = digits.len().div_ceil(3);
+    let first = digits.len() - 3 * count.saturating_sub(1);
+    let groups = std::iter::once(&digits[..first])
+        .chain(digits[first..].chunks(3))
+        .zip((0..count).rev())
+        .map(|(group, scale)| (to_number(group), scale));
+
+    let mut s = String::new();
+    // writing into a String never fails
+    write_groups(&mut WordWriter::new(&mut s, options), groups).unwrap();
+    Ok(s)
+}
+
+/// Write words for groups of 0 to 999, starting with the largest scale,
+/// where scale 1 is thousands, and 2 is millions.
+pub(crate) fn write_groups<W: fmt::Write, I: Iterator<Item = (u64, usize)>>(
+    out: &mut WordWriter<W>,
+    groups: I,
+) -> fmt::Result {
+    let and_word = out.options().style == Style::GB;
+    let mut written = false; // words before, e.g. "minus", don't count
+    for (value, scale) in groups.filter(|(value, _)| *value > 0) {
+        write_english_hundreds(out, value, and_word, scale == 0 && written)?;
+        write_scale(out, scale)?;
+        written = true;
+    }
+    match written {
+        true => Ok(()),
+        false => out.word(map_with(0, out.options())),
+    }
+}
+
+fn write_scale<W: fmt::Write>(out: &mut WordWriter<W>, scale: usize) -> fmt::Result {
+    let illion = scale.wrapping_sub(1);
+    match scale {
+        0 => Ok(()),
+        1 => out.word(map(1_000)),
+        2..=10 => {
+            out.word(SMALL[illion])?;
+            out.join("illion")
+        }
+        11..=100 => {
+            match illion % 10 {
+                0 => out.word(TENS[illion / 10])?,
+                units => {
+                    out.word(UNITS[units])?;
+                    out.join(TENS[illion / 10])?;
+                }
+            }
+            out.join("illion")
+        }
+        MAX_SCALE => out.word("centillion"),
+        _ => {
+            for word in &["times", "ten", "to", "the", "power", "of"] {
+                out.word(word)?;
+            }
+            let exponent = 3 * scale as u64;
+            let groups = (0..7)
+                .rev()
+                .map(|scale| (exponent / 1_000_u64.pow(scale) % 1_000, scale as usize));
+            write_groups(out, groups)
+        }
+    }
+}
+
+fn to_number(digits: &[u8]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |num, digit| num * 10 + (digit - b'0') as u64)
+}
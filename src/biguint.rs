@@ -0,0 +1,90 @@
+//! Words for [num-bigint](https://docs.rs/num-bigint) BigUint, with the "bigint" feature.
+
+use crate::big::write_groups;
+use crate::locale::WordWriter;
+use crate::ordinal::{plain, to_ordinal};
+use crate::Options;
+use num_bigint::BigUint;
+use std::convert::TryFrom;
+use std::fmt;
+
+// 18 digits, the largest power of 1000 in a u64
+const CHUNK: u64 = 1_000_000_000_000_000_000;
+
+/// Returns String with words given a BigUint.
+///
+/// Uses the same scale words as [shortscale_bigint](./fn.shortscale_bigint.html),
+/// without converting the number to a string first.  
+/// Requires the "bigint" feature.
+///
+/// # Example
+/// ```
+/// use num_bigint::BigUint;
+/// use shortscale::shortscale_for;
+///
+/// let num = BigUint::from(10_u32).pow(21) * 7_u32;
+/// assert_eq!(shortscale_for(&num), "seven sextillion");
+/// ```
+pub fn shortscale_for(num: &BigUint) -> String {
+    shortscale_for_with(num, &Options::new())
+}
+
+/// Same as shortscale_for but with [Options](./struct.Options.html).
+pub fn shortscale_for_with(num: &BigUint, options: &Options) -> String {
+    let mut s = String::new();
+    // writing into a String never fails
+    shortscale_for_write(&mut s, num, options).unwrap();
+    s
+}
+
+/// Same as shortscale_for but writes words into any fmt::Write, with [Options](./struct.Options.html).
+pub fn shortscale_for_write<W: fmt::Write>(
+    w: &mut W,
+    num: &BigUint,
+    options: &Options,
+) -> fmt::Result {
+    let chunks = to_chunks(num);
+    let groups = chunks.iter().enumerate().rev().flat_map(|(i, chunk)| {
+        (0..6)
+            .rev()
+            .map(move |k| (chunk / 1_000_u64.pow(k) % 1_000, 6 * i + k as usize))
+    });
+    write_groups(&mut WordWriter::new(w, options), groups)
+}
+
+/// Returns String with ordinal words given a BigUint, e.g. "one quintillionth".
+///
+/// # Example
+/// ```
+/// use num_bigint::BigUint;
+/// use shortscale::shortscale_ordinal_for;
+///
+/// let num = BigUint::from(10_u32).pow(18) + 2_u32;
+/// assert_eq!(shortscale_ordinal_for(&num), "one quintillion and second");
+/// ```
+pub fn shortscale_ordinal_for(num: &BigUint) -> String {
+    shortscale_ordinal_for_with(num, &Options::new())
+}
+
+/// Same as shortscale_ordinal_for but with [Options](./struct.Options.html).
+pub fn shortscale_ordinal_for_with(num: &BigUint, options: &Options) -> String {
+    let mut s = String::new();
+    // write in lower case with "zero" and spaces to find the ordinal word
+    let lower = plain(options);
+    shortscale_for_write(&mut s, num, &lower).unwrap();
+    to_ordinal(&mut s, 0, options);
+    s
+}
+
+// groups of 18 digits, starting with the smallest
+fn to_chunks(num: &BigUint) -> Vec<u64> {
+    let chunk = BigUint::from(CHUNK);
+    let mut chunks = Vec::new();
+    let mut rest = num.clone();
+    while rest >= chunk {
+        chunks.push(u64::try_from(&rest % &chunk).unwrap());
+        rest /= &chunk;
+    }
+    chunks.push(u64::try_from(rest).unwrap());
+    chunks
+}
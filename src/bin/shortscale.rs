@@ -0,0 +1,167 @@
+//! Command line tool, requires the "cli" feature.
+//!
+//! ```txt
+//! cargo install shortscale --features cli
+//! shortscale 420000999015
+//! echo "four hundred and twenty" | shortscale --parse
+//! ```
+
+use shortscale::{shortscale_ordinal_with, shortscale_parse, shortscale_with, Options, Style};
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const USAGE: &str = "\
+Usage: shortscale [OPTIONS] [NUMBER...]
+
+Converts numbers into English words.
+Reads one number per line from stdin if there are no NUMBER arguments.
+
+Options:
+  --ordinal         write ordinal words, e.g. \"twenty second\"
+  --style=us|gb     American or British style, also en-US or en-GB (default gb)
+  --parse           convert words into numbers, arguments are joined into one phrase
+  --json            write one JSON object per line
+  -h, --help        show this help
+";
+
+#[derive(Default)]
+struct Args {
+    ordinal: bool,
+    parse: bool,
+    json: bool,
+    options: Options,
+    inputs: Vec<String>,
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("shortscale: {}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    let mut failed = false;
+
+    let mut run = |input: &str| {
+        let input = input.trim();
+        if input.is_empty() {
+            return;
+        }
+        let result = convert(&args, input);
+        failed |= result.is_err();
+        let written = match (args.json, result) {
+            (false, Ok(output)) => writeln!(out, "{}", output),
+            (false, Err(message)) => {
+                eprintln!("shortscale: {}: {}", input, message);
+                Ok(())
+            }
+            (true, Ok(output)) if args.parse => {
+                writeln!(out, r#"{{"input":{},"output":{}}}"#, json(input), output)
+            }
+            (true, Ok(output)) => {
+                writeln!(
+                    out,
+                    r#"{{"input":{},"output":{}}}"#,
+                    json(input),
+                    json(&output)
+                )
+            }
+            (true, Err(message)) => {
+                writeln!(
+                    out,
+                    r#"{{"input":{},"error":{}}}"#,
+                    json(input),
+                    json(&message)
+                )
+            }
+        };
+        if let Err(e) = written {
+            eprintln!("shortscale: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match (args.inputs.is_empty(), args.parse) {
+        (false, true) => run(&args.inputs.join(" ")),
+        (false, false) => args.inputs.iter().for_each(|input| run(input)),
+        (true, _) => {
+            for line in io::stdin().lock().lines() {
+                match line {
+                    Ok(line) => run(&line),
+                    Err(e) => {
+                        eprintln!("shortscale: {}", e);
+                        process::exit(1);
+                    }
+                }
+            }
+        }
+    }
+
+    out.flush().ok();
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    for arg in args {
+        match arg.as_str() {
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            "--ordinal" => parsed.ordinal = true,
+            "--parse" => parsed.parse = true,
+            "--json" => parsed.json = true,
+            _ if arg.starts_with("--style=") => {
+                let style: Style = arg["--style=".len()..]
+                    .parse()
+                    .map_err(|e| format!("{}", e))?;
+                parsed.options = parsed.options.style(style);
+            }
+            _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+            _ => parsed.inputs.push(arg),
+        }
+    }
+    Ok(parsed)
+}
+
+fn convert(args: &Args, input: &str) -> Result<String, String> {
+    if args.parse {
+        return shortscale_parse(input)
+            .map(|num| num.to_string())
+            .map_err(|e| e.to_string());
+    }
+    let num: u64 = input
+        .replace(&[',', '_'][..], "")
+        .parse()
+        .map_err(|_| "not a number".to_string())?;
+    match args.ordinal {
+        true => Ok(shortscale_ordinal_with(num, &args.options)),
+        false => Ok(shortscale_with(num, &args.options)),
+    }
+}
+
+// JSON string with escapes
+fn json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
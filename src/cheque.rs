@@ -0,0 +1,160 @@
+//! Amounts for writing on bank cheques.
+
+use crate::{shortscale_with, Case, Error, Options, Style};
+
+/// Options for [shortscale_cheque](./fn.shortscale_cheque.html).
+///
+/// The default is "Dollars" in American style and title case with hyphens,
+/// followed by "***", without a line width.
+///
+/// # Example
+/// ```
+/// use shortscale::ChequeOptions;
+///
+/// let options = ChequeOptions::new().currency("Euros").filler('-').width(40).wrap(true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ChequeOptions {
+    pub currency: &'static str,
+    pub filler: char,
+    pub width: usize,
+    pub wrap: bool,
+    pub options: Options,
+}
+
+impl Default for ChequeOptions {
+    fn default() -> Self {
+        Self {
+            currency: "Dollars",
+            filler: '*',
+            width: 0,
+            wrap: false,
+            options: Options::new()
+                .style(Style::US)
+                .hyphenate(true)
+                .case(Case::Title),
+        }
+    }
+}
+
+impl ChequeOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the currency name after the cents, e.g. "Pounds".
+    pub fn currency(mut self, currency: &'static str) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Set the character which fills the end of the line.
+    pub fn filler(mut self, filler: char) -> Self {
+        self.filler = filler;
+        self
+    }
+
+    /// Set the line width in characters, filling the last line with the filler.
+    /// 0 means no limit, with three filler characters at the end.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Wrap words onto more lines instead of returning an error when the width is too small.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set the [Options](./struct.Options.html) for the words.
+    /// The letter case applies to the whole line, including "and" and the currency.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// Returns the amount for a bank cheque given a number of cents.
+///
+/// Lines are separated by "\n" when wrapping.
+/// Returns [Error::Width](./enum.Error.html#variant.Width) when the text does not fit.
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_cheque, ChequeOptions};
+///
+/// assert_eq!(
+///     shortscale_cheque(123_456, &ChequeOptions::new()).unwrap(),
+///     "One Thousand Two Hundred Thirty-Four and 56/100 Dollars ***"
+/// );
+/// assert_eq!(
+///     shortscale_cheque(123_456, &ChequeOptions::new().width(32).wrap(true)).unwrap(),
+///     "One Thousand Two Hundred\nThirty-Four and 56/100 Dollars *"
+/// );
+/// ```
+pub fn shortscale_cheque(cents: u64, cheque: &ChequeOptions) -> Result<String, Error> {
+    let case = cheque.options.case;
+    let text = format!(
+        "{} {} {:02}/100 {}",
+        shortscale_with(cents / 100, &cheque.options),
+        cased("and", case),
+        cents % 100,
+        cased(cheque.currency, case)
+    );
+    let width = cheque.width;
+    if width == 0 {
+        let filler: String = std::iter::repeat(cheque.filler).take(3).collect();
+        return Ok(format!("{} {}", text, filler));
+    }
+
+    let mut s = String::new();
+    let mut line = 0; // characters on the current line
+    for word in text.split(' ') {
+        let len = word.chars().count();
+        match (line, line + 1 + len <= width) {
+            (0, _) => (),
+            (_, true) => {
+                s.push(' ');
+                line += 1;
+            }
+            (_, false) if cheque.wrap => {
+                s.push('\n');
+                line = 0;
+            }
+            (_, false) => {
+                let needed = text.chars().count();
+                return Err(Error::Width { width, needed });
+            }
+        }
+        if len > width {
+            return Err(Error::Width { width, needed: len });
+        }
+        s.push_str(word);
+        line += len;
+    }
+
+    // fill the rest of the last line after a space
+    if line + 2 <= width {
+        s.push(' ');
+        s.extend(std::iter::repeat(cheque.filler).take(width - line - 1));
+    }
+    Ok(s)
+}
+
+// same letter case as the words, "and" stays lower case in title case
+fn cased(word: &str, case: Case) -> String {
+    match case {
+        Case::Upper => word.to_uppercase(),
+        Case::Title if word != "and" => {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        }
+        Case::Title => word.to_string(),
+        Case::Lower | Case::Sentence => word.to_lowercase(),
+    }
+}
@@ -0,0 +1,52 @@
+//! Compact numbers with digits and a scale word, e.g. "1.2 million".
+
+use crate::{map, MAX};
+
+/// Returns digits with a scale word, rounded to decimals, e.g. "1.2 million".
+///
+/// Numbers below one thousand are written as digits, trailing zeros after the point are removed,
+/// and decimals are limited to 18. Larger values than 999_999_999_999_999_999 return "(big number)".
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_compact;
+///
+/// assert_eq!(shortscale_compact(1_234_567, 1), "1.2 million");
+/// assert_eq!(shortscale_compact(999_900_000_000_000, 1), "999.9 trillion");
+/// assert_eq!(shortscale_compact(2_000, 2), "2 thousand");
+/// assert_eq!(shortscale_compact(420, 1), "420");
+/// ```
+pub fn shortscale_compact(num: u64, decimals: u8) -> String {
+    if num > MAX {
+        return map(num).to_string();
+    }
+    if num < 1_000 {
+        return num.to_string();
+    }
+    let decimals = u32::from(decimals.min(18));
+    let unit = 10_u128.pow(decimals);
+    let scaled = u128::from(num) * unit;
+
+    let mut scale: u64 = 1_000;
+    while num / scale >= 1_000 {
+        scale *= 1_000;
+    }
+    let mut value = (scaled + u128::from(scale) / 2) / u128::from(scale);
+    // rounded up to 1000, e.g. 999_999 is "1 million"
+    if value >= 1_000 * unit {
+        match scale.checked_mul(1_000).filter(|next| *next <= MAX) {
+            Some(next) => {
+                scale = next;
+                value = (scaled + u128::from(scale) / 2) / u128::from(scale);
+            }
+            None => value = scaled / u128::from(scale),
+        }
+    }
+
+    let whole = value / unit;
+    let fraction = format!("{:0width$}", value % unit, width = decimals as usize);
+    match fraction.trim_end_matches('0') {
+        "" => format!("{} {}", whole, map(scale)),
+        fraction => format!("{}.{} {}", whole, fraction, map(scale)),
+    }
+}
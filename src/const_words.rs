@@ -0,0 +1,120 @@
+//! Words at compile time, with const fn and the shortscale! macro.
+
+use crate::{map, MAX, MAX_LEN};
+
+/// Words in a fixed size buffer, returned by [shortscale_const](./fn.shortscale_const.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstWords {
+    buf: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl ConstWords {
+    /// Returns the words as &str.
+    pub const fn as_str(&self) -> &str {
+        match std::str::from_utf8(self.as_bytes()) {
+            Ok(s) => s,
+            Err(_) => panic!("words are always UTF-8"),
+        }
+    }
+
+    pub(crate) const fn new() -> Self {
+        ConstWords {
+            buf: [0; MAX_LEN],
+            len: 0,
+        }
+    }
+
+    pub(crate) const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len).0
+    }
+
+    const fn word(mut self, word: &str) -> Self {
+        if self.len > 0 {
+            self.buf[self.len] = b' ';
+            self.len += 1;
+        }
+        let bytes = word.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            self.buf[self.len] = bytes[i];
+            self.len += 1;
+            i += 1;
+        }
+        self
+    }
+}
+
+/// Same as shortscale, but evaluated at compile time when used in a const.
+///
+/// The [shortscale!](./macro.shortscale.html) macro returns the words as &'static str.
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_const, ConstWords};
+///
+/// const WORDS: ConstWords = shortscale_const(420);
+/// assert_eq!(WORDS.as_str(), "four hundred and twenty");
+/// ```
+pub const fn shortscale_const(num: u64) -> ConstWords {
+    let words = ConstWords::new();
+    if num == 0 || num > MAX {
+        return words.word(map(num));
+    }
+    let mut words = words;
+    let mut divisor = 1_000_000_000_000_000;
+    while divisor > 0 {
+        let group = num / divisor % 1_000;
+        if group > 0 {
+            words = const_group(words, group, divisor);
+        }
+        divisor /= 1_000;
+    }
+    words
+}
+
+// same as write_english_group for the default Options
+pub(crate) const fn const_group(mut words: ConstWords, group: u64, divisor: u64) -> ConstWords {
+    let hundreds = group / 100;
+    let num = group % 100;
+    if hundreds > 0 {
+        words = words.word(map(hundreds)).word(map(100));
+    }
+    if num > 0 {
+        if hundreds > 0 || (divisor == 1 && words.len > 0) {
+            words = words.word("and");
+        }
+        words = match num {
+            1..=20 => words.word(map(num)),
+            _ => match num % 10 {
+                0 => words.word(map(num)),
+                unit => words.word(map(num - unit)).word(map(unit)),
+            },
+        };
+    }
+    match divisor {
+        1 => words,
+        _ => words.word(map(divisor)),
+    }
+}
+
+/// Returns words as &'static str, computed at compile time.
+///
+/// The argument must be a constant expression.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale;
+///
+/// const LIVES: &str = shortscale!(9);
+/// assert_eq!(LIVES, "nine");
+/// assert_eq!(shortscale!(420), "four hundred and twenty");
+/// ```
+#[macro_export]
+macro_rules! shortscale {
+    ($num:expr) => {{
+        const WORDS: $crate::ConstWords = $crate::shortscale_const($num);
+        const STR: &str = WORDS.as_str();
+        STR
+    }};
+}
@@ -0,0 +1,81 @@
+//! Counts of things, e.g. "twenty two files".
+
+use crate::{shortscale_string_writer, ToWords, MAX};
+
+/// Returns words for a count followed by the singular or plural noun, e.g. "twenty two files".
+///
+/// Without a plural, the noun gets basic English plural endings,
+/// e.g. "boxes", "batches", "categories", or "files".
+/// An empty noun returns the number alone.
+/// Numbers larger than 999_999_999_999_999_999 continue with quintillion,
+/// like [ToWords](./trait.ToWords.html).
+///
+/// # Example
+/// ```
+/// use shortscale::count_of;
+///
+/// assert_eq!(count_of(0, "item", None), "zero items");
+/// assert_eq!(count_of(1, "file", None), "one file");
+/// assert_eq!(count_of(22, "file", None), "twenty two files");
+/// assert_eq!(count_of(3, "child", Some("children")), "three children");
+/// ```
+pub fn count_of(num: u64, singular: &str, plural: Option<&str>) -> String {
+    let mut s = String::new();
+    match num {
+        0..=MAX => shortscale_string_writer(&mut s, num),
+        _ => s.push_str(&num.to_words()),
+    }
+    push_noun(&mut s, num, singular, plural);
+    s
+}
+
+/// Same as count_of but with digits, e.g. "22 files".
+///
+/// # Example
+/// ```
+/// use shortscale::count_of_numeric;
+///
+/// assert_eq!(count_of_numeric(22, "file", None), "22 files");
+/// ```
+pub fn count_of_numeric(num: u64, singular: &str, plural: Option<&str>) -> String {
+    let mut s = num.to_string();
+    push_noun(&mut s, num, singular, plural);
+    s
+}
+
+fn push_noun(s: &mut String, num: u64, singular: &str, plural: Option<&str>) {
+    if singular.is_empty() {
+        return;
+    }
+    s.push(' ');
+    match (num, plural) {
+        (1, _) => s.push_str(singular),
+        (_, Some(plural)) => s.push_str(plural),
+        (_, None) => push_plural(s, singular),
+    }
+}
+
+fn push_plural(s: &mut String, singular: &str) {
+    let sibilant = ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|ending| singular.ends_with(ending));
+    let mut chars = singular.chars().rev();
+    let consonant_y = match (chars.next(), chars.next()) {
+        (Some('y'), Some(ch)) => !"aeiou".contains(ch),
+        _ => false,
+    };
+    match (sibilant, consonant_y) {
+        (true, _) => {
+            s.push_str(singular);
+            s.push_str("es");
+        }
+        (_, true) => {
+            s.push_str(&singular[..singular.len() - 1]);
+            s.push_str("ies");
+        }
+        _ => {
+            s.push_str(singular);
+            s.push('s');
+        }
+    }
+}
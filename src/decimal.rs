@@ -0,0 +1,120 @@
+//! Decimal numbers with a fractional part, e.g. "3.14".
+
+use crate::{map, shortscale_string_writer, Error, MAX};
+
+/// How to read the digits after the decimal point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalStyle {
+    /// Read each digit, e.g. "three point one four".
+    Point,
+    /// Read the digits as a fraction, e.g. "three and fourteen hundredths".
+    Fraction,
+}
+
+/// Returns String with words given a decimal number like "3.14".
+///
+/// The integer part supports 0 to 999_999_999_999_999_999 and may be omitted (".5").
+/// [DecimalStyle::Fraction](./enum.DecimalStyle.html) supports up to 17 fractional digits.
+/// Input with characters other than digits and a single "." returns an error.
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_decimal, DecimalStyle};
+///
+/// assert_eq!(
+///     shortscale_decimal("3.14", DecimalStyle::Point).unwrap(),
+///     "three point one four"
+/// );
+/// assert_eq!(
+///     shortscale_decimal("3.14", DecimalStyle::Fraction).unwrap(),
+///     "three and fourteen hundredths"
+/// );
+/// ```
+pub fn shortscale_decimal(num: &str, style: DecimalStyle) -> Result<String, Error> {
+    let (integer, fraction) = split_decimal(num)?;
+    let mut s = String::new();
+    match style {
+        DecimalStyle::Point => push_point(&mut s, integer, fraction),
+        DecimalStyle::Fraction => push_fraction(&mut s, integer, fraction)?,
+    }
+    Ok(s)
+}
+
+// returns the integer value and the fractional digits
+fn split_decimal(num: &str) -> Result<(u64, &str), Error> {
+    if num.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    let (digits, fraction) = match num.find('.') {
+        Some(i) => (&num[..i], &num[i + 1..]),
+        None => (num, ""),
+    };
+    check_digits(digits, 0)?;
+    check_digits(fraction, digits.len() + 1)?;
+    if digits.is_empty() && fraction.is_empty() {
+        return Err(Error::InvalidChar { ch: '.', offset: 0 });
+    }
+    let integer = match digits {
+        "" => 0,
+        _ => digits.parse().map_err(|_| Error::out_of_range())?,
+    };
+    if integer > MAX {
+        return Err(Error::out_of_range());
+    }
+    Ok((integer, fraction))
+}
+
+fn check_digits(digits: &str, offset: usize) -> Result<(), Error> {
+    match digits.char_indices().find(|(_, ch)| !ch.is_ascii_digit()) {
+        Some((i, ch)) => Err(Error::InvalidChar {
+            ch,
+            offset: offset + i,
+        }),
+        None => Ok(()),
+    }
+}
+
+fn push_point(s: &mut String, integer: u64, fraction: &str) {
+    shortscale_string_writer(s, integer);
+    if fraction.is_empty() {
+        return;
+    }
+    s.push_str(" point");
+    for digit in fraction.bytes() {
+        s.push(' ');
+        s.push_str(map((digit - b'0') as u64));
+    }
+}
+
+fn push_fraction(s: &mut String, integer: u64, fraction: &str) -> Result<(), Error> {
+    if fraction.len() > 17 {
+        return Err(Error::out_of_range());
+    }
+    let numerator: u64 = match fraction {
+        "" => 0,
+        _ => fraction.parse().unwrap(), // checked digits
+    };
+    if numerator == 0 {
+        shortscale_string_writer(s, integer);
+        return Ok(());
+    }
+    if integer > 0 {
+        shortscale_string_writer(s, integer);
+        s.push_str(" and ");
+    }
+    shortscale_string_writer(s, numerator);
+    s.push(' ');
+    push_denominator(s, fraction.len(), numerator > 1);
+    Ok(())
+}
+
+// "tenth", "hundredths", "ten thousandths", ...
+fn push_denominator(s: &mut String, digits: usize, plural: bool) {
+    let mut words = String::new();
+    shortscale_string_writer(&mut words, 10_u64.pow(digits as u32));
+    s.push_str(words.strip_prefix("one ").unwrap_or(&words));
+    s.push_str("th");
+    if plural {
+        s.push('s');
+    }
+}
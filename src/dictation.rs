@@ -0,0 +1,158 @@
+//! Number dictation exercises, with answer checking.
+//!
+//! # Example
+//! ```
+//! use shortscale::dictation::Dictation;
+//!
+//! for exercise in Dictation::new(1..=100, 42).take(3) {
+//!     assert!(exercise.check(&exercise.words));
+//!     assert!(exercise.check_ordinal(&exercise.ordinal_words));
+//! }
+//! ```
+
+use crate::rng::XorShift;
+use crate::{shortscale_ordinal_with, shortscale_with, Options, MAX};
+use std::ops::RangeInclusive;
+
+/// A number with its words and ordinal words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exercise {
+    pub number: u64,
+    pub words: String,
+    pub ordinal_words: String,
+    options: Options,
+}
+
+impl Exercise {
+    /// Returns true if the answer matches the words,
+    /// see [check_answer_with](./fn.check_answer_with.html).
+    pub fn check(&self, answer: &str) -> bool {
+        matches(answer, self.number, false, &self.options)
+    }
+
+    /// Returns true if the answer matches the ordinal words,
+    /// see [check_answer_with](./fn.check_answer_with.html).
+    pub fn check_ordinal(&self, answer: &str) -> bool {
+        matches(answer, self.number, true, &self.options)
+    }
+}
+
+/// Endless iterator of [Exercises](./struct.Exercise.html) with random numbers in a range.
+///
+/// The same range and seed always yield the same numbers.
+/// The range is limited to 999_999_999_999_999_999.
+#[derive(Debug, Clone)]
+pub struct Dictation {
+    rng: XorShift,
+    start: u64,
+    span: u64,
+    options: Options,
+}
+
+impl Dictation {
+    pub fn new(range: RangeInclusive<u64>, seed: u64) -> Self {
+        let end = (*range.end()).min(MAX);
+        let start = (*range.start()).min(end);
+        Dictation {
+            rng: XorShift::new(seed),
+            start,
+            span: end - start + 1,
+            options: Options::new(),
+        }
+    }
+
+    /// Set the [Options](../struct.Options.html) for the words.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl Iterator for Dictation {
+    type Item = Exercise;
+
+    fn next(&mut self) -> Option<Exercise> {
+        // scale into the range without the bias of %
+        let offset = (u128::from(self.rng.next_u64()) * u128::from(self.span)) >> 64;
+        let number = self.start + offset as u64;
+        Some(Exercise {
+            number,
+            words: shortscale_with(number, &self.options),
+            ordinal_words: shortscale_ordinal_with(number, &self.options),
+            options: self.options,
+        })
+    }
+}
+
+/// Returns true if the answer matches the words for a number.
+///
+/// Matching ignores case, hyphens, commas, extra spaces, and "and",
+/// so both British and American style answers are accepted.
+///
+/// # Example
+/// ```
+/// use shortscale::dictation::check_answer;
+///
+/// assert!(check_answer(121, "One hundred twenty-one"));
+/// assert!(check_answer(121, "one hundred and twenty one"));
+/// assert!(!check_answer(121, "one hundred and twelve"));
+/// ```
+pub fn check_answer(num: u64, answer: &str) -> bool {
+    check_answer_with(num, answer, &Options::new())
+}
+
+/// Same as check_answer but compared with words written with [Options](../struct.Options.html).
+///
+/// The separator and conjunction from the options are ignored like spaces and "and".
+///
+/// # Example
+/// ```
+/// use shortscale::dictation::check_answer_with;
+/// use shortscale::{Options, ZeroWord};
+///
+/// let options = Options::new().zero(ZeroWord::Nought).separator("_");
+/// assert!(check_answer_with(0, "nought", &options));
+/// assert!(check_answer_with(420, "four_hundred_and_twenty", &options));
+/// ```
+pub fn check_answer_with(num: u64, answer: &str, options: &Options) -> bool {
+    num <= MAX && matches(answer, num, false, options)
+}
+
+/// Same as check_answer but for ordinal words, e.g. "twenty-first".
+pub fn check_ordinal_answer(num: u64, answer: &str) -> bool {
+    check_ordinal_answer_with(num, answer, &Options::new())
+}
+
+/// Same as check_answer_with but for ordinal words.
+pub fn check_ordinal_answer_with(num: u64, answer: &str, options: &Options) -> bool {
+    num <= MAX && matches(answer, num, true, options)
+}
+
+fn matches(answer: &str, num: u64, ordinal: bool, options: &Options) -> bool {
+    let words = |options: &Options| {
+        if ordinal {
+            shortscale_ordinal_with(num, options)
+        } else {
+            shortscale_with(num, options)
+        }
+    };
+    // words run together with an empty separator, so they are compared with spaces,
+    // or as written with the same options
+    let spaced = options.separator(" ");
+    normalize(answer, options) == normalize(&words(&spaced), &spaced)
+        || answer.to_lowercase() == words(options).to_lowercase()
+}
+
+// lower case words without "and" or the conjunction
+fn normalize(s: &str, options: &Options) -> Vec<String> {
+    let s = match options.separator {
+        "" | " " => s.to_lowercase(),
+        separator => s.to_lowercase().replace(&separator.to_lowercase(), " "),
+    };
+    s.split(|ch: char| ch.is_whitespace() || ch == '-' || ch == ',')
+        .filter(|word| {
+            !word.is_empty() && *word != "and" && !word.eq_ignore_ascii_case(options.conjunction)
+        })
+        .map(str::to_string)
+        .collect()
+}
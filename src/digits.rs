@@ -0,0 +1,173 @@
+//! Digit strings like phone numbers and codes, read in groups of digits.
+
+use crate::{map, shortscale_string_writer, Error, MAX};
+
+/// Returns String with words given a string of digits, read in groups of 1 to 18 digits.
+///
+/// With `group` 1, each digit is read separately, zero is read as "oh",
+/// and repeated digits are collapsed into "double" and "triple".
+/// With larger groups, each group is read as a number, e.g. "2024" in pairs is "twenty twenty four",
+/// and a single digit left over joins the last group.
+/// Groups starting with 0 are read digit by digit.
+///
+/// Whitespace and hyphens separate chunks, which are read separately and joined with ", ".
+/// Other characters, and groups of 0 or larger than 18, return an error.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_digits;
+///
+/// assert_eq!(shortscale_digits("2024", 2).unwrap(), "twenty twenty four");
+/// assert_eq!(shortscale_digits("100", 1).unwrap(), "one double-oh");
+/// assert_eq!(
+///     shortscale_digits("07700 900123", 1).unwrap(),
+///     "oh double-seven double-oh, nine double-oh one two three"
+/// );
+/// ```
+pub fn shortscale_digits(digits: &str, group: usize) -> Result<String, Error> {
+    if let Some((offset, ch)) = digits
+        .char_indices()
+        .find(|(_, ch)| !(ch.is_ascii_digit() || is_separator(*ch)))
+    {
+        return Err(Error::InvalidChar { ch, offset });
+    }
+    if group == 0 || group > 18 {
+        return Err(Error::OutOfRange { min: 1, max: 18 });
+    }
+    let mut s = String::new();
+    for chunk in digits.split(is_separator).filter(|c| !c.is_empty()) {
+        if !s.is_empty() {
+            s.push_str(", ");
+        }
+        push_chunk(&mut s, chunk.as_bytes(), group);
+    }
+    if s.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    Ok(s)
+}
+
+/// Returns String with words given a number read in chunks of digits, joined by a separator,
+/// e.g. for phone numbers in voice prompts.
+///
+/// Chunks have `group` digits from the left, and a single digit left over joins the last chunk.
+/// Each digit is read separately, except round chunks like "800" or "1000" which are read as numbers.
+/// The separator can be a word or a pause marker, and should include any spaces.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_grouped;
+///
+/// assert_eq!(
+///     shortscale_grouped(8_005_551_234, 3, ", "),
+///     "eight hundred, five five five, one two three four"
+/// );
+/// assert_eq!(shortscale_grouped(1_020_304, 2, " - "), "one oh - two oh - three oh four");
+/// ```
+pub fn shortscale_grouped(num: u64, group: usize, sep: &str) -> String {
+    let digits = num.to_string();
+    let digits = digits.as_bytes();
+    let group = group.max(1);
+    let mut s = String::new();
+    let mut start = 0;
+    while start < digits.len() {
+        let mut end = (start + group).min(digits.len());
+        if digits.len() - end == 1 && group > 1 {
+            end = digits.len();
+        }
+        if start > 0 {
+            s.push_str(sep);
+        }
+        let chunk = &digits[start..end];
+        if chunk.len() >= 3 && chunk[1..].iter().all(|d| *d == b'0') && to_number(chunk) <= MAX {
+            shortscale_string_writer(&mut s, to_number(chunk));
+        } else {
+            for (i, digit) in chunk.iter().enumerate() {
+                if i > 0 {
+                    s.push(' ');
+                }
+                s.push_str(digit_word(*digit));
+            }
+        }
+        start = end;
+    }
+    s
+}
+
+fn is_separator(ch: char) -> bool {
+    ch.is_whitespace() || ch == '-'
+}
+
+fn push_chunk(s: &mut String, chunk: &[u8], group: usize) {
+    if group == 1 {
+        return push_each_digit(s, chunk);
+    }
+    // groups read digit by digit are joined, so repeated digits collapse across groups
+    let mut each_start = None;
+    let mut start = 0;
+    while start < chunk.len() {
+        let mut end = (start + group).min(chunk.len());
+        if chunk.len() - end == 1 {
+            end = chunk.len();
+        }
+        let digits = &chunk[start..end];
+        if digits[0] == b'0' || to_number(digits) > MAX {
+            each_start.get_or_insert(start);
+        } else {
+            if let Some(each_start) = each_start.take() {
+                push_digits(s, &chunk[each_start..start], each_start);
+            }
+            if start > 0 {
+                s.push(' ');
+            }
+            shortscale_string_writer(s, to_number(digits));
+        }
+        start = end;
+    }
+    if let Some(each_start) = each_start {
+        push_digits(s, &chunk[each_start..], each_start);
+    }
+}
+
+fn push_digits(s: &mut String, digits: &[u8], start: usize) {
+    if start > 0 {
+        s.push(' ');
+    }
+    push_each_digit(s, digits);
+}
+
+// one digit at a time, collapsing repeated digits into "double" and "triple"
+fn push_each_digit(s: &mut String, digits: &[u8]) {
+    let mut i = 0;
+    while i < digits.len() {
+        let run = digits[i..].iter().take_while(|d| **d == digits[i]).count();
+        let take = match run {
+            1 | 2 => run,
+            4 => 2, // double double
+            _ => 3,
+        };
+        if i > 0 {
+            s.push(' ');
+        }
+        match take {
+            2 => s.push_str("double-"),
+            3 => s.push_str("triple-"),
+            _ => (),
+        }
+        s.push_str(digit_word(digits[i]));
+        i += take;
+    }
+}
+
+fn digit_word(digit: u8) -> &'static str {
+    match digit {
+        b'0' => "oh",
+        _ => map((digit - b'0') as u64),
+    }
+}
+
+fn to_number(digits: &[u8]) -> u64 {
+    digits
+        .iter()
+        .fold(0, |num, digit| num * 10 + (digit - b'0') as u64)
+}
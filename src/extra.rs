@@ -54,6 +54,9 @@ pub fn shortscale_display(num: u64) -> String {
 /// directly mutating an existing string, rother than going
 /// through the formatter code.
 ///
+/// The supported Display implementation with options is now
+/// [shortscale::NumWords](../struct.NumWords.html).
+///
 /// # Example
 /// ```
 /// use shortscale;
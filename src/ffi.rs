@@ -0,0 +1,137 @@
+//! C API, requires the "capi" feature.
+//!
+//! Words are written as NUL terminated UTF-8 into a buffer provided by the caller.
+//! Functions return the length of the words without the NUL terminator,
+//! or one of the negative `SHORTSCALE_ERR_*` codes.
+//!
+//! Build a static or shared library with
+//! `cargo rustc --lib --release --features capi --crate-type staticlib` (or `cdylib`),
+//! and generate the header with `cbindgen --config cbindgen.toml --output shortscale.h`.
+//!
+//! ```c
+//! char buf[SHORTSCALE_BUF_LEN];
+//! ptrdiff_t len = shortscale_c(420, buf, sizeof buf);
+//! if (len >= 0) puts(buf); // "four hundred and twenty"
+//! ```
+
+use crate::{shortscale_ordinal, shortscale_parse, shortscale_write, MAX, MAX_LEN};
+use std::ffi::CStr;
+use std::fmt::{self, Write};
+use std::os::raw::c_char;
+use std::slice;
+
+/// Buffer size which fits any words from shortscale_c or shortscale_ordinal_c,
+/// including the NUL terminator.
+///
+/// Ordinal words are at most 2 bytes longer than [MAX_LEN](../constant.MAX_LEN.html),
+/// e.g. "seventh" for "seven".
+pub const SHORTSCALE_BUF_LEN: usize = MAX_LEN + 3;
+
+/// A pointer argument was NULL.
+pub const SHORTSCALE_ERR_NULL: isize = -1;
+
+/// The buffer is too small for the words and the NUL terminator.
+pub const SHORTSCALE_ERR_BUFFER: isize = -2;
+
+/// The number is larger than 999_999_999_999_999_999.
+pub const SHORTSCALE_ERR_RANGE: isize = -3;
+
+/// The words could not be parsed into a number.
+pub const SHORTSCALE_ERR_PARSE: isize = -4;
+
+/// Writes words given a number into `buf`, e.g. 420 is "four hundred and twenty".
+///
+/// Returns the length of the words, or a negative error code.
+///
+/// # Safety
+/// `buf` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn shortscale_c(num: u64, buf: *mut c_char, len: usize) -> isize {
+    if num > MAX {
+        return SHORTSCALE_ERR_RANGE;
+    }
+    write_c(buf, len, |w| shortscale_write(w, num))
+}
+
+/// Writes ordinal words given a number into `buf`, e.g. 22 is "twenty second".
+///
+/// Returns the length of the words, or a negative error code.
+///
+/// # Safety
+/// `buf` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn shortscale_ordinal_c(num: u64, buf: *mut c_char, len: usize) -> isize {
+    if num > MAX {
+        return SHORTSCALE_ERR_RANGE;
+    }
+    write_c(buf, len, |w| w.write_str(&shortscale_ordinal(num)))
+}
+
+/// Parses NUL terminated words into a number stored in `num`.
+///
+/// Returns 0, or a negative error code.
+///
+/// # Safety
+/// `words` must be a valid NUL terminated string and `num` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn shortscale_parse_c(words: *const c_char, num: *mut u64) -> isize {
+    if words.is_null() || num.is_null() {
+        return SHORTSCALE_ERR_NULL;
+    }
+    let parsed = CStr::from_ptr(words)
+        .to_str()
+        .ok()
+        .and_then(|words| shortscale_parse(words).ok());
+    match parsed {
+        Some(n) => {
+            *num = n;
+            0
+        }
+        None => SHORTSCALE_ERR_PARSE,
+    }
+}
+
+unsafe fn write_c<F>(buf: *mut c_char, len: usize, write: F) -> isize
+where
+    F: FnOnce(&mut BufWriter) -> fmt::Result,
+{
+    if buf.is_null() {
+        return SHORTSCALE_ERR_NULL;
+    }
+    if len == 0 {
+        return SHORTSCALE_ERR_BUFFER;
+    }
+    let mut w = BufWriter {
+        buf: slice::from_raw_parts_mut(buf as *mut u8, len),
+        pos: 0,
+    };
+    let result = write(&mut w);
+    // always NUL terminate, an empty string on error
+    let end = match result {
+        Ok(()) => w.pos,
+        Err(_) => 0,
+    };
+    w.buf[end] = 0;
+    match result {
+        Ok(()) => end as isize,
+        Err(_) => SHORTSCALE_ERR_BUFFER,
+    }
+}
+
+// fixed size writer which keeps one byte for the NUL terminator
+struct BufWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl fmt::Write for BufWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.pos + s.len();
+        if end >= self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.pos..end].copy_from_slice(s.as_bytes());
+        self.pos = end;
+        Ok(())
+    }
+}
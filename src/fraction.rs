@@ -0,0 +1,77 @@
+//! Fractions, e.g. "three quarters".
+
+use crate::{shortscale_ordinal_string_writer, shortscale_string_writer, ToWords, MAX};
+
+/// Returns words for a fraction, e.g. "three quarters" or "seven sixteenths".
+///
+/// Denominators up to 99 and powers of ten are written as ordinals,
+/// plural unless the numerator is one. Other denominators are written
+/// with "over", e.g. "one over three hundred", and a denominator of 1
+/// returns only the numerator.
+/// Numbers larger than 999_999_999_999_999_999 continue with quintillion.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_fraction;
+///
+/// assert_eq!(shortscale_fraction(1, 2), "one half");
+/// assert_eq!(shortscale_fraction(3, 4), "three quarters");
+/// assert_eq!(shortscale_fraction(7, 16), "seven sixteenths");
+/// assert_eq!(shortscale_fraction(1, 300), "one over three hundred");
+/// ```
+pub fn shortscale_fraction(numerator: u64, denominator: u64) -> String {
+    let mut s = String::new();
+    push_words(&mut s, numerator);
+    if denominator == 1 {
+        return s;
+    }
+    let plural = numerator != 1;
+    s.push(' ');
+    match denominator {
+        2 if plural => s.push_str("halves"),
+        2 => s.push_str("half"),
+        4 if plural => s.push_str("quarters"),
+        4 => s.push_str("quarter"),
+        3..=99 => push_ordinal(&mut s, denominator, plural),
+        _ if is_power_of_ten(denominator) => {
+            // "hundredths", not "one hundredths"
+            let start = s.len();
+            push_ordinal(&mut s, denominator, plural);
+            if s[start..].starts_with("one ") {
+                s.replace_range(start..start + "one ".len(), "");
+            }
+        }
+        _ => {
+            s.push_str("over ");
+            push_words(&mut s, denominator);
+        }
+    }
+    s
+}
+
+fn push_words(s: &mut String, num: u64) {
+    match num {
+        0..=MAX => shortscale_string_writer(s, num),
+        _ => s.push_str(&num.to_words()),
+    }
+}
+
+fn push_ordinal(s: &mut String, num: u64, plural: bool) {
+    match num {
+        0..=MAX => shortscale_ordinal_string_writer(s, num),
+        _ => s.push_str(&num.to_ordinal_words()),
+    }
+    if plural {
+        s.push('s');
+    }
+}
+
+fn is_power_of_ten(mut num: u64) -> bool {
+    while num >= 10 {
+        match num % 10 {
+            0 => num /= 10,
+            _ => return false,
+        }
+    }
+    num == 1
+}
@@ -0,0 +1,50 @@
+//! Same output as the JavaScript [shortscale](https://github.com/jldec/shortscale) package,
+//! requires the "js_compat" feature.
+//!
+//! For porting from Node, with the same words, the same "and" rules,
+//! the same overflow text "(big number)", and the same supported range.
+//!
+//! # Example
+//! ```
+//! use shortscale::js_compat;
+//!
+//! assert_eq!(js_compat::shortscale(420.0), "four hundred and twenty");
+//! assert_eq!(js_compat::shortscale(1e18), "(big number)");
+//! ```
+
+use crate::{map, MAX};
+
+/// Largest number with words, the same as in JavaScript.
+pub const MAX_NUMBER: u64 = MAX;
+
+/// Largest integer which JavaScript numbers represent exactly, `Number.MAX_SAFE_INTEGER`.
+pub const MAX_SAFE_INTEGER: u64 = 9_007_199_254_740_991;
+
+/// Text for numbers outside the supported range.
+pub const BIG_NUMBER: &str = map(u64::MAX);
+
+/// Returns words given a JavaScript number.
+///
+/// Integers from 0 to 999_999_999_999_999_999 return words, using the exact value of the f64
+/// above `MAX_SAFE_INTEGER`, like JavaScript.
+/// Anything else, including negative, fractional, and NaN values, returns "(big number)".
+pub fn shortscale(num: f64) -> String {
+    match to_integer(num) {
+        Some(num) => shortscale_u64(num),
+        None => BIG_NUMBER.to_string(),
+    }
+}
+
+/// Same as shortscale for integers, e.g. from a JavaScript BigInt.
+pub fn shortscale_u64(num: u64) -> String {
+    crate::shortscale(num)
+}
+
+// the integer value of a number in the supported range
+fn to_integer(num: f64) -> Option<u64> {
+    // MAX as f64 rounds up to 1e18, which is out of range
+    match (0.0..1e18).contains(&num) && num.fract() == 0.0 {
+        true => Some(num as u64),
+        false => None,
+    }
+}
@@ -0,0 +1,38 @@
+//! Length of words, without writing them.
+
+use crate::table::group_len;
+use crate::{map, MAX};
+
+/// Returns the length in bytes of the words given an unsigned integer,
+/// without writing the words.
+///
+/// The length is never more than [MAX_LEN](./constant.MAX_LEN.html).
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale, shortscale_len};
+///
+/// assert_eq!(shortscale_len(420), shortscale(420).len());
+/// let mut buf = String::with_capacity(shortscale_len(420));
+/// ```
+pub const fn shortscale_len(num: u64) -> usize {
+    if num == 0 || num > MAX {
+        return map(num).len();
+    }
+    // same parts as table::for_each_part, with a space between parts
+    let mut len = 0;
+    let mut divisor = 1_000_000_000_000_000;
+    while divisor > 1 {
+        let value = num / divisor % 1_000;
+        if value > 0 {
+            len += group_len(value) + 1 + map(divisor).len() + 1;
+        }
+        divisor /= 1_000;
+    }
+    match (num % 1_000, len) {
+        (0, _) => len - 1,
+        (value, 0) => group_len(value),
+        (value @ 1..=99, _) => len + "and ".len() + group_len(value),
+        (value, _) => len + group_len(value),
+    }
+}
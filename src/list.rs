@@ -0,0 +1,107 @@
+//! English lists, e.g. "one, two and three".
+
+use crate::{shortscale_with, Options};
+
+/// Options for [join_words_list](./fn.join_words_list.html).
+///
+/// The default is "and" before the last item, without an Oxford comma.
+///
+/// # Example
+/// ```
+/// use shortscale::{ListOptions, Options, Style};
+///
+/// let options = ListOptions::new()
+///     .conjunction("or")
+///     .oxford_comma(true)
+///     .options(Options::new().style(Style::US));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ListOptions {
+    pub conjunction: &'static str,
+    pub oxford_comma: bool,
+    pub options: Options,
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self {
+            conjunction: "and",
+            oxford_comma: false,
+            options: Options::new(),
+        }
+    }
+}
+
+impl ListOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the conjunction before the last item, e.g. "or".
+    pub fn conjunction(mut self, conjunction: &'static str) -> Self {
+        self.conjunction = conjunction;
+        self
+    }
+
+    /// Add a comma before the conjunction in lists of 3 or more items, e.g. "one, two, and three".
+    pub fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+        self.oxford_comma = oxford_comma;
+        self
+    }
+
+    /// Set the [Options](./struct.Options.html) for the words.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// Returns words for a list of numbers, e.g. "one, two and three".
+///
+/// # Example
+/// ```
+/// use shortscale::{join_words_list, ListOptions};
+///
+/// assert_eq!(join_words_list(&[1, 2, 3], &ListOptions::new()), "one, two and three");
+/// assert_eq!(
+///     join_words_list(&[1, 2, 3], &ListOptions::new().oxford_comma(true)),
+///     "one, two, and three"
+/// );
+/// assert_eq!(join_words_list(&[7, 11], &ListOptions::new()), "seven and eleven");
+/// ```
+pub fn join_words_list(items: &[u64], list: &ListOptions) -> String {
+    let items: Vec<String> = items
+        .iter()
+        .map(|num| shortscale_with(*num, &list.options))
+        .collect();
+    let mut s = String::new();
+    push_list(&mut s, &items, list.conjunction, list.oxford_comma);
+    s
+}
+
+// joins items with commas and the conjunction before the last item,
+// with a comma before the conjunction for the Oxford comma and 3 or more items
+pub(crate) fn push_list<S: AsRef<str>>(
+    s: &mut String,
+    items: &[S],
+    conjunction: &str,
+    oxford: bool,
+) {
+    let last = items.len().saturating_sub(1);
+    for (i, item) in items.iter().enumerate() {
+        match i {
+            0 => (),
+            _ if i < last => s.push_str(", "),
+            _ => {
+                if oxford && last > 1 {
+                    s.push(',');
+                }
+                s.push(' ');
+                s.push_str(conjunction);
+                s.push(' ');
+            }
+        }
+        s.push_str(item.as_ref());
+    }
+}
@@ -0,0 +1,355 @@
+//! Language rules for writing numbers, used by
+//! [shortscale_locale](../fn.shortscale_locale.html).
+//!
+//! The writer splits numbers into groups of digits, and calls
+//! [Locale::write_group](./trait.Locale.html#tymethod.write_group)
+//! for each non-zero group, starting with the largest scale.
+//!
+//! Implement the Locale trait to add another language.
+//!
+//! # Example
+//! ```
+//! use shortscale::locale::{EnUs, Group, Locale, WordWriter};
+//! use shortscale::shortscale_locale;
+//! use std::fmt;
+//!
+//! // English digits for the hundreds and units, without scale words
+//! struct Pairs;
+//!
+//! impl Locale for Pairs {
+//!     fn group_digits(&self, _scale: u32) -> u32 {
+//!         2
+//!     }
+//!
+//!     fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+//!         EnUs.write_group(out, Group { scale: 0, ..group })
+//!     }
+//! }
+//!
+//! assert_eq!(shortscale_locale(1984, &Pairs), "nineteen eighty four");
+//! ```
+
+use crate::{map, map_with, Case, Options, MAX};
+use std::fmt;
+
+#[cfg(feature = "de")]
+mod de;
+#[cfg(feature = "de")]
+pub use de::De;
+
+#[cfg(feature = "es")]
+mod es;
+#[cfg(feature = "es")]
+pub use es::{Es, Gender};
+
+#[cfg(feature = "fr")]
+mod fr;
+#[cfg(feature = "fr")]
+pub use fr::Fr;
+
+#[cfg(feature = "ja")]
+mod ja;
+#[cfg(feature = "ja")]
+pub use ja::Ja;
+
+/// Rules for writing numbers in one language.
+pub trait Locale {
+    /// Number of digits in the group at scale, 3 for thousands, 4 for myriads.
+    ///
+    /// Scale 0 is the units group, e.g. Indian numbering has 3 digits at scale 0,
+    /// and 2 digits at larger scales.
+    fn group_digits(&self, _scale: u32) -> u32 {
+        3
+    }
+
+    /// Largest number with words.
+    fn max(&self) -> u64 {
+        MAX
+    }
+
+    /// Word for 0.
+    fn zero(&self) -> &'static str {
+        map(0)
+    }
+
+    /// Word for 0 with [Options](../struct.Options.html), the same as zero unless overridden.
+    ///
+    /// The English locales use the zero word option, e.g. "nought".
+    fn zero_with(&self, _options: &Options) -> &'static str {
+        self.zero()
+    }
+
+    /// Words for numbers larger than max.
+    fn big_number(&self) -> &'static str {
+        map(u64::MAX)
+    }
+
+    /// Write one non-zero group of digits, followed by its scale word.
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result;
+}
+
+/// Non-zero group of digits, passed to [Locale::write_group](./trait.Locale.html#tymethod.write_group).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Group {
+    /// Value of the group, e.g. 420 for 420_000_999_015.
+    pub value: u64,
+    /// Position of the group, 0 for units, 1 for thousands (or myriads), 2 for millions, ...
+    pub scale: u32,
+    /// The whole number being written.
+    pub num: u64,
+}
+
+/// Writes words separated by spaces or the separator option, passed to
+/// [Locale::write_group](./trait.Locale.html#tymethod.write_group).
+///
+/// Words are written in the letter case of the [Options](../struct.Options.html).
+pub struct WordWriter<'a, W: fmt::Write> {
+    w: &'a mut W,
+    len: usize,
+    options: &'a Options,
+    capitalize: bool,
+    plain: bool, // lower case, separated by spaces
+}
+
+impl<'a, W: fmt::Write> WordWriter<'a, W> {
+    pub fn new(w: &'a mut W, options: &'a Options) -> Self {
+        Self {
+            w,
+            len: 0,
+            options,
+            capitalize: options.case != Case::Lower,
+            plain: options.case == Case::Lower && options.separator == " ",
+        }
+    }
+
+    /// Write a word, preceded by a space unless it is the first.
+    #[inline]
+    pub fn word(&mut self, word: &str) -> fmt::Result {
+        if !self.plain {
+            return self.word_with_options(word);
+        }
+        if self.len > 0 {
+            self.w.write_char(' ')?;
+            self.len += " ".len();
+        }
+        self.w.write_str(word)?;
+        self.len += word.len();
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn word_with_options(&mut self, word: &str) -> fmt::Result {
+        if self.len > 0 {
+            self.w.write_str(self.options.separator)?;
+            self.len += self.options.separator.len();
+        }
+        if self.options.case != Case::Lower {
+            return self.word_cased(word);
+        }
+        self.w.write_str(word)?;
+        self.len += word.len();
+        Ok(())
+    }
+
+    /// Write a word or suffix without a space.
+    #[inline]
+    pub fn join(&mut self, word: &str) -> fmt::Result {
+        if self.options.case != Case::Lower {
+            return self.join_cased(word);
+        }
+        self.w.write_str(word)?;
+        self.len += word.len();
+        Ok(())
+    }
+
+    fn word_cased(&mut self, word: &str) -> fmt::Result {
+        if self.options.case == Case::Title {
+            self.capitalize = word != self.options.conjunction;
+        }
+        self.join_cased(word)
+    }
+
+    // capitalize the first letter, and in title case each letter after a space or hyphen
+    #[inline(never)]
+    fn join_cased(&mut self, word: &str) -> fmt::Result {
+        self.len += word.len();
+        for ch in word.chars() {
+            match (self.options.case, self.capitalize && ch.is_alphabetic(), ch) {
+                (Case::Upper, _, _) | (_, true, _) => {
+                    self.capitalize = false;
+                    ch.to_uppercase().try_for_each(|ch| self.w.write_char(ch))?;
+                }
+                (Case::Title, false, ' ') | (Case::Title, false, '-') => {
+                    self.capitalize = true;
+                    self.w.write_char(ch)?;
+                }
+                _ => self.w.write_char(ch)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Write text as is, without a space or letter case, e.g. markup between words.
+    pub fn raw(&mut self, text: &str) -> fmt::Result {
+        self.w.write_str(text)
+    }
+
+    /// True if nothing has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn options(&self) -> &Options {
+        self.options
+    }
+}
+
+/// Write words for num using locale rules.
+pub(crate) fn write_locale<W: fmt::Write, L: Locale>(
+    w: &mut W,
+    num: u64,
+    locale: &L,
+    options: &Options,
+) -> fmt::Result {
+    let mut out = WordWriter::new(w, options);
+    if num == 0 {
+        return out.word(locale.zero_with(options));
+    }
+    if num > locale.max() {
+        return out.word(locale.big_number());
+    }
+    let base = |scale| 10_u64.pow(locale.group_digits(scale));
+    let mut scale = 0;
+    let mut divisor: u64 = 1;
+    while let Some(next) = divisor.checked_mul(base(scale)) {
+        if num < next {
+            break;
+        }
+        divisor = next;
+        scale += 1;
+    }
+    loop {
+        let value = num / divisor % base(scale);
+        if value > 0 {
+            locale.write_group(&mut out, Group { value, scale, num })?;
+        }
+        if scale == 0 {
+            return Ok(());
+        }
+        scale -= 1;
+        divisor /= base(scale);
+    }
+}
+
+/// British English, "one hundred and one".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnGb;
+
+/// American English, "one hundred one".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnUs;
+
+impl Locale for EnGb {
+    fn zero_with(&self, options: &Options) -> &'static str {
+        map_with(0, options)
+    }
+
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+        write_english_group(out, group, true, map(1_000_u64.pow(group.scale)))
+    }
+}
+
+impl Locale for EnUs {
+    fn zero_with(&self, options: &Options) -> &'static str {
+        map_with(0, options)
+    }
+
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+        write_english_group(out, group, false, map(1_000_u64.pow(group.scale)))
+    }
+}
+
+/// Indian English, with lakh and crore, "one crore twenty three lakh".
+///
+/// Digits are grouped 2-2-3, e.g. 1,23,45,678.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnIn;
+
+impl Locale for EnIn {
+    fn zero_with(&self, options: &Options) -> &'static str {
+        map_with(0, options)
+    }
+
+    fn group_digits(&self, scale: u32) -> u32 {
+        match scale {
+            0 => 3,
+            _ => 2,
+        }
+    }
+
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+        let scale_word = match group.scale {
+            1 => "thousand",
+            2 => "lakh",
+            3 => "crore",
+            4 => "arab",
+            5 => "kharab",
+            6 => "nil",
+            7 => "padma",
+            _ => "shankh",
+        };
+        write_english_group(out, group, true, scale_word)
+    }
+}
+
+fn write_english_group<W: fmt::Write>(
+    out: &mut WordWriter<W>,
+    group: Group,
+    and_word: bool,
+    scale_word: &str,
+) -> fmt::Result {
+    write_english_hundreds(out, group.value, and_word, group.scale == 0)?;
+    match group.scale {
+        0 => Ok(()),
+        _ => out.word(scale_word),
+    }
+}
+
+// 1 to 999 without a scale word, units is true for the last group
+pub(crate) fn write_english_hundreds<W: fmt::Write>(
+    out: &mut WordWriter<W>,
+    value: u64,
+    and_word: bool,
+    units: bool,
+) -> fmt::Result {
+    let hundreds = value / 100;
+    let num = value % 100;
+    // "and" after hundreds, and before the last tens and units
+    let and_word = and_word && (hundreds > 0 || (units && !out.is_empty()));
+    if hundreds > 0 {
+        out.word(map(hundreds))?;
+        out.word(map(100))?;
+    }
+    if num > 0 {
+        let conjunction = out.options().conjunction;
+        if and_word && !conjunction.is_empty() {
+            out.word(conjunction)?;
+        }
+        match num {
+            1..=20 => out.word(map(num))?,
+            _ => {
+                out.word(map(num / 10 * 10))?;
+                let num = num % 10;
+                match (num, out.options().hyphenate) {
+                    (0, _) => (),
+                    (_, true) => {
+                        out.join("-")?;
+                        out.join(map(num))?;
+                    }
+                    (_, false) => out.word(map(num))?,
+                }
+            }
+        }
+    }
+    Ok(())
+}
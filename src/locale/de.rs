@@ -0,0 +1,137 @@
+//! German, requires the "de" feature.
+
+use super::{Group, Locale, WordWriter};
+use std::fmt;
+
+/// German, "vierhundertzwanzig Milliarden neunhundertneunundneunzigtausendfünfzehn".
+///
+/// Numbers below one million are written as one word, with units before tens,
+/// e.g. "einundzwanzig". Larger scales use the long scale names
+/// Million, Milliarde, Billion, and Billiarde.
+///
+/// # Example
+/// ```
+/// use shortscale::locale::De;
+/// use shortscale::shortscale_locale;
+///
+/// assert_eq!(shortscale_locale(21, &De), "einundzwanzig");
+/// assert_eq!(shortscale_locale(2_000_001, &De), "zwei Millionen eins");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct De;
+
+impl Locale for De {
+    fn zero(&self) -> &'static str {
+        "null"
+    }
+
+    fn big_number(&self) -> &'static str {
+        "(große Zahl)"
+    }
+
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+        match group.scale {
+            0 => {
+                // units are joined to thousands, e.g. "eintausendeins"
+                let thousands = group.num / 1_000 % 1_000;
+                write_compound(out, group.value, "eins", thousands > 0)
+            }
+            1 => {
+                write_compound(out, group.value, "ein", false)?;
+                out.join("tausend")
+            }
+            _ => {
+                write_compound(out, group.value, "eine", false)?;
+                out.word(scale_name(group.scale, group.value > 1))
+            }
+        }
+    }
+}
+
+// 1 to 999 as one word, with the given word for a final 1
+fn write_compound<W: fmt::Write>(
+    out: &mut WordWriter<W>,
+    num: u64,
+    one: &'static str,
+    joined: bool,
+) -> fmt::Result {
+    let mut joined = joined;
+    let mut piece = |out: &mut WordWriter<W>, word: &str| {
+        let result = match joined {
+            true => out.join(word),
+            false => out.word(word),
+        };
+        joined = true;
+        result
+    };
+    let hundreds = num / 100;
+    let rest = num % 100;
+    if hundreds > 0 {
+        piece(out, units(hundreds, "ein"))?;
+        piece(out, "hundert")?;
+    }
+    match rest {
+        0 => Ok(()),
+        1 => piece(out, one),
+        2..=19 => piece(out, units(rest, one)),
+        _ => {
+            let unit = rest % 10;
+            if unit > 0 {
+                piece(out, units(unit, "ein"))?;
+                piece(out, "und")?;
+            }
+            piece(out, tens(rest / 10))
+        }
+    }
+}
+
+fn units(num: u64, one: &'static str) -> &'static str {
+    match num {
+        1 => one,
+        2 => "zwei",
+        3 => "drei",
+        4 => "vier",
+        5 => "fünf",
+        6 => "sechs",
+        7 => "sieben",
+        8 => "acht",
+        9 => "neun",
+        10 => "zehn",
+        11 => "elf",
+        12 => "zwölf",
+        13 => "dreizehn",
+        14 => "vierzehn",
+        15 => "fünfzehn",
+        16 => "sechzehn",
+        17 => "siebzehn",
+        18 => "achtzehn",
+        _ => "neunzehn",
+    }
+}
+
+fn tens(num: u64) -> &'static str {
+    match num {
+        2 => "zwanzig",
+        3 => "dreißig",
+        4 => "vierzig",
+        5 => "fünfzig",
+        6 => "sechzig",
+        7 => "siebzig",
+        8 => "achtzig",
+        _ => "neunzig",
+    }
+}
+
+// long scale names, 10^6 is Million, 10^9 is Milliarde
+fn scale_name(scale: u32, plural: bool) -> &'static str {
+    match (scale, plural) {
+        (2, false) => "Million",
+        (2, true) => "Millionen",
+        (3, false) => "Milliarde",
+        (3, true) => "Milliarden",
+        (4, false) => "Billion",
+        (4, true) => "Billionen",
+        (_, false) => "Billiarde",
+        (_, true) => "Billiarden",
+    }
+}
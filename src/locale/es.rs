@@ -0,0 +1,195 @@
+//! Spanish, requires the "es" feature.
+
+use super::{Group, Locale, WordWriter};
+use std::fmt;
+
+/// Spanish, "novecientos noventa y nueve millones novecientos noventa y nueve mil…".
+///
+/// "uno" is shortened to "un" before scale words, e.g. "veintiún mil" and "un millón".
+/// Numbers from 21 to 29 are one word, e.g. "veintiuno".
+/// Larger scales use the long scale, 10^9 is "mil millones" and 10^12 is "billón".
+///
+/// Use [Gender::Feminine](./enum.Gender.html) for counting feminine nouns,
+/// e.g. "doscientas una" (personas).
+///
+/// # Example
+/// ```
+/// use shortscale::locale::{Es, Gender};
+/// use shortscale::shortscale_locale;
+///
+/// assert_eq!(shortscale_locale(21, &Es::default()), "veintiuno");
+/// assert_eq!(shortscale_locale(21_000, &Es::default()), "veintiún mil");
+/// assert_eq!(
+///     shortscale_locale(201, &Es { gender: Gender::Feminine }),
+///     "doscientas una"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Es {
+    pub gender: Gender,
+}
+
+/// Grammatical gender of the counted noun.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Gender {
+    #[default]
+    Masculine,
+    Feminine,
+}
+
+// forms of 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum One {
+    Uno,
+    Un,
+    Una,
+}
+
+impl Locale for Es {
+    fn zero(&self) -> &'static str {
+        "cero"
+    }
+
+    fn big_number(&self) -> &'static str {
+        "(número grande)"
+    }
+
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+        let feminine = self.gender == Gender::Feminine;
+        let value = group.value;
+        match group.scale {
+            0 if feminine => write_hundreds(out, value, One::Una, true),
+            0 => write_hundreds(out, value, One::Uno, false),
+            // thousands, "mil millones", and "mil billones"
+            1 | 3 | 5 => {
+                let one = match feminine && group.scale == 1 {
+                    true => One::Una,
+                    false => One::Un,
+                };
+                if value > 1 {
+                    write_hundreds(out, value, one, one == One::Una)?;
+                }
+                out.word("mil")?;
+                // "millones" or "billones" when the next group is zero
+                let next = group.num / 1_000_u64.pow(group.scale - 1) % 1_000;
+                match (group.scale, next) {
+                    (3, 0) => out.word("millones"),
+                    (5, 0) => out.word("billones"),
+                    _ => Ok(()),
+                }
+            }
+            // "millón" and "billón", plural when the count including "mil" is more than one
+            _ => {
+                write_hundreds(out, value, One::Un, false)?;
+                let thousands = group.num / 1_000_u64.pow(group.scale + 1) % 1_000;
+                let plural = value > 1 || thousands > 0;
+                out.word(match (group.scale, plural) {
+                    (2, false) => "millón",
+                    (2, true) => "millones",
+                    (_, false) => "billón",
+                    (_, true) => "billones",
+                })
+            }
+        }
+    }
+}
+
+fn write_hundreds<W: fmt::Write>(
+    out: &mut WordWriter<W>,
+    num: u64,
+    one: One,
+    feminine: bool,
+) -> fmt::Result {
+    let hundreds = num / 100;
+    let rest = num % 100;
+    match (hundreds, rest) {
+        (0, _) => (),
+        (1, 0) => out.word("cien")?,
+        (1, _) => out.word("ciento")?,
+        _ => {
+            out.word(hundreds_name(hundreds))?;
+            out.join(match feminine {
+                true => "as",
+                false => "os",
+            })?;
+        }
+    }
+    match rest {
+        0 => Ok(()),
+        1..=29 => out.word(units(rest, one)),
+        _ => {
+            out.word(tens_name(rest / 10))?;
+            match rest % 10 {
+                0 => Ok(()),
+                unit => {
+                    out.word("y")?;
+                    out.word(units(unit, one))
+                }
+            }
+        }
+    }
+}
+
+fn units(num: u64, one: One) -> &'static str {
+    match (num, one) {
+        (1, One::Uno) => "uno",
+        (1, One::Un) => "un",
+        (1, One::Una) => "una",
+        (2, _) => "dos",
+        (3, _) => "tres",
+        (4, _) => "cuatro",
+        (5, _) => "cinco",
+        (6, _) => "seis",
+        (7, _) => "siete",
+        (8, _) => "ocho",
+        (9, _) => "nueve",
+        (10, _) => "diez",
+        (11, _) => "once",
+        (12, _) => "doce",
+        (13, _) => "trece",
+        (14, _) => "catorce",
+        (15, _) => "quince",
+        (16, _) => "dieciséis",
+        (17, _) => "diecisiete",
+        (18, _) => "dieciocho",
+        (19, _) => "diecinueve",
+        (20, _) => "veinte",
+        (21, One::Uno) => "veintiuno",
+        (21, One::Un) => "veintiún",
+        (21, One::Una) => "veintiuna",
+        (22, _) => "veintidós",
+        (23, _) => "veintitrés",
+        (24, _) => "veinticuatro",
+        (25, _) => "veinticinco",
+        (26, _) => "veintiséis",
+        (27, _) => "veintisiete",
+        (28, _) => "veintiocho",
+        _ => "veintinueve",
+    }
+}
+
+fn tens_name(tens: u64) -> &'static str {
+    match tens {
+        3 => "treinta",
+        4 => "cuarenta",
+        5 => "cincuenta",
+        6 => "sesenta",
+        7 => "setenta",
+        8 => "ochenta",
+        _ => "noventa",
+    }
+}
+
+// stem without the gender ending
+fn hundreds_name(hundreds: u64) -> &'static str {
+    match hundreds {
+        2 => "doscient",
+        3 => "trescient",
+        4 => "cuatrocient",
+        5 => "quinient",
+        6 => "seiscient",
+        7 => "setecient",
+        8 => "ochocient",
+        _ => "novecient",
+    }
+}
@@ -0,0 +1,141 @@
+//! French, requires the "fr" feature.
+
+use super::{Group, Locale, WordWriter};
+use std::fmt;
+
+/// French, "quatre cent vingt milliards neuf cent quatre-vingt-dix-neuf mille quinze".
+///
+/// Tens and units are joined with hyphens, e.g. "soixante-et-onze".
+/// Numbers from 70 to 99 are counted in twenties, e.g. "quatre-vingt-dix-neuf".
+/// "cent" and "vingt" are plural at the end of a number or before millions,
+/// e.g. "deux cents" and "quatre-vingts", while "mille" is invariable.
+/// Larger scales use the long scale names million, milliard, billion, and billiard.
+///
+/// # Example
+/// ```
+/// use shortscale::locale::Fr;
+/// use shortscale::shortscale_locale;
+///
+/// assert_eq!(shortscale_locale(71, &Fr), "soixante-et-onze");
+/// assert_eq!(shortscale_locale(200, &Fr), "deux cents");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Fr;
+
+impl Locale for Fr {
+    fn zero(&self) -> &'static str {
+        "zéro"
+    }
+
+    fn big_number(&self) -> &'static str {
+        "(grand nombre)"
+    }
+
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+        // plural "cents" and "vingts" except before "mille"
+        let plural = group.scale != 1;
+        match (group.value, group.scale) {
+            (1, 1) => (), // "mille", not "un mille"
+            _ => write_hundreds(out, group.value, plural)?,
+        }
+        match group.scale {
+            0 => Ok(()),
+            1 => out.word("mille"),
+            _ => out.word(scale_name(group.scale, group.value > 1)),
+        }
+    }
+}
+
+fn write_hundreds<W: fmt::Write>(out: &mut WordWriter<W>, num: u64, plural: bool) -> fmt::Result {
+    let hundreds = num / 100;
+    let rest = num % 100;
+    match hundreds {
+        0 => (),
+        1 => out.word("cent")?,
+        _ => {
+            out.word(units(hundreds))?;
+            out.word(match (rest, plural) {
+                (0, true) => "cents",
+                _ => "cent",
+            })?;
+        }
+    }
+    match rest {
+        0 => Ok(()),
+        _ => write_tens(out, rest, plural),
+    }
+}
+
+// 1 to 99, joined with hyphens
+fn write_tens<W: fmt::Write>(out: &mut WordWriter<W>, num: u64, plural: bool) -> fmt::Result {
+    let (tens, unit) = match num / 10 {
+        // 70 to 79 is sixty plus ten to nineteen, 90 to 99 is eighty plus ten to nineteen
+        7 | 9 => (num / 10 - 1, num % 10 + 10),
+        _ => (num / 10, num % 10),
+    };
+    match tens {
+        0 | 1 => return out.word(units(num)),
+        8 if unit == 0 && plural => return out.word("quatre-vingts"),
+        _ => out.word(tens_name(tens))?,
+    }
+    match (unit, tens) {
+        (0, _) => Ok(()),
+        (1, 2..=6) | (11, 6) => {
+            out.join("-et-")?;
+            out.join(units(unit))
+        }
+        _ => {
+            out.join("-")?;
+            out.join(units(unit))
+        }
+    }
+}
+
+fn units(num: u64) -> &'static str {
+    match num {
+        1 => "un",
+        2 => "deux",
+        3 => "trois",
+        4 => "quatre",
+        5 => "cinq",
+        6 => "six",
+        7 => "sept",
+        8 => "huit",
+        9 => "neuf",
+        10 => "dix",
+        11 => "onze",
+        12 => "douze",
+        13 => "treize",
+        14 => "quatorze",
+        15 => "quinze",
+        16 => "seize",
+        17 => "dix-sept",
+        18 => "dix-huit",
+        _ => "dix-neuf",
+    }
+}
+
+fn tens_name(tens: u64) -> &'static str {
+    match tens {
+        2 => "vingt",
+        3 => "trente",
+        4 => "quarante",
+        5 => "cinquante",
+        6 => "soixante",
+        _ => "quatre-vingt",
+    }
+}
+
+// long scale names, 10^9 is milliard, 10^12 is billion
+fn scale_name(scale: u32, plural: bool) -> &'static str {
+    match (scale, plural) {
+        (2, false) => "million",
+        (2, true) => "millions",
+        (3, false) => "milliard",
+        (3, true) => "milliards",
+        (4, false) => "billion",
+        (4, true) => "billions",
+        (_, false) => "billiard",
+        (_, true) => "billiards",
+    }
+}
@@ -0,0 +1,71 @@
+//! Japanese, requires the "ja" feature.
+
+use super::{Group, Locale, WordWriter};
+use std::fmt;
+
+/// Japanese kanji numerals, "一億二千三百四十五万六千七百八十九".
+///
+/// Digits are grouped by myriads (10^4) with the scale words 万, 億, 兆, and 京,
+/// written without spaces. Supports numbers up to u64::MAX.
+///
+/// # Example
+/// ```
+/// use shortscale::locale::Ja;
+/// use shortscale::shortscale_locale;
+///
+/// assert_eq!(shortscale_locale(123_456_789, &Ja), "一億二千三百四十五万六千七百八十九");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Ja;
+
+impl Locale for Ja {
+    fn group_digits(&self, _scale: u32) -> u32 {
+        4
+    }
+
+    fn max(&self) -> u64 {
+        u64::MAX
+    }
+
+    fn zero(&self) -> &'static str {
+        "零"
+    }
+
+    fn write_group<W: fmt::Write>(&self, out: &mut WordWriter<W>, group: Group) -> fmt::Result {
+        for (unit, name) in [(1_000, "千"), (100, "百"), (10, "十")].iter() {
+            match group.value / unit % 10 {
+                0 => (),
+                1 => out.join(name)?, // 十, not 一十
+                digit => {
+                    out.join(digit_name(digit))?;
+                    out.join(name)?;
+                }
+            }
+        }
+        match group.value % 10 {
+            0 => (),
+            digit => out.join(digit_name(digit))?,
+        }
+        match group.scale {
+            0 => Ok(()),
+            1 => out.join("万"),
+            2 => out.join("億"),
+            3 => out.join("兆"),
+            _ => out.join("京"),
+        }
+    }
+}
+
+fn digit_name(digit: u64) -> &'static str {
+    match digit {
+        1 => "一",
+        2 => "二",
+        3 => "三",
+        4 => "四",
+        5 => "五",
+        6 => "六",
+        7 => "七",
+        8 => "八",
+        _ => "九",
+    }
+}
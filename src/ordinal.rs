@@ -0,0 +1,172 @@
+//! Ordinal numbers, in words like "twenty second", or numeric like "22nd".
+
+use crate::locale::WordWriter;
+use crate::{shortscale_write_with, Case, Options, ZeroWord, MAX};
+
+/// Returns String with ordinal words given an unsigned integer.
+///
+/// Only the last word changes, e.g. "twenty two" becomes "twenty second".
+/// Larger values than 999_999_999_999_999_999 return "(big number)".
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_ordinal;
+///
+/// assert_eq!(shortscale_ordinal(22), "twenty second");
+/// assert_eq!(shortscale_ordinal(1_000_000), "one millionth");
+/// ```
+pub fn shortscale_ordinal(num: u64) -> String {
+    let mut s = String::new();
+    shortscale_ordinal_string_writer(&mut s, num);
+    s
+}
+
+/// Same as shortscale_ordinal but with [Options](./struct.Options.html).
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_ordinal_with, Options, Style};
+///
+/// assert_eq!(
+///     shortscale_ordinal_with(121, &Options::new().style(Style::US).hyphenate(true)),
+///     "one hundred twenty-first"
+/// );
+/// ```
+pub fn shortscale_ordinal_with(num: u64, options: &Options) -> String {
+    let mut s = String::new();
+    write_ordinal(&mut s, num, options);
+    s
+}
+
+/// Same as shortscale_ordinal but writes words into mutable String.
+pub fn shortscale_ordinal_string_writer(s: &mut String, num: u64) {
+    write_ordinal(s, num, &Options::new());
+}
+
+fn write_ordinal(s: &mut String, num: u64, options: &Options) {
+    let start = s.len();
+    // write in lower case with "zero" and spaces to find the ordinal word
+    let lower = plain(options);
+    // writing into a String never fails
+    shortscale_write_with(s, num, &lower).unwrap();
+    match num {
+        0..=MAX => to_ordinal(s, start, options),
+        _ => recase(s, start, options),
+    }
+}
+
+// changes the last word after start into an ordinal, and writes the words in the case of options
+pub(crate) fn to_ordinal(s: &mut String, start: usize, options: &Options) {
+    let last = start + s[start..].rfind([' ', '-']).map_or(0, |i| i + 1);
+    match ordinal_word(&s[last..]) {
+        Some(ordinal) => {
+            s.truncate(last);
+            s.push_str(ordinal);
+        }
+        // larger scale words like "quintillion"
+        None => s.push_str("th"),
+    }
+    recase(s, start, options);
+}
+
+// options for writing words which can be changed into an ordinal
+pub(crate) fn plain(options: &Options) -> Options {
+    let default = Options::new();
+    options
+        .case(Case::Lower)
+        .zero(ZeroWord::Zero)
+        .separator(default.separator)
+        .conjunction(default.conjunction)
+}
+
+// writes plain words after start in the case, separator, and conjunction of options
+fn recase(s: &mut String, start: usize, options: &Options) {
+    if plain(options) == *options {
+        return;
+    }
+    let words = s.split_off(start);
+    let mut out = WordWriter::new(s, options);
+    for word in words.split(' ') {
+        match word {
+            "and" if options.conjunction.is_empty() => (),
+            "and" => out.word(options.conjunction).unwrap(),
+            _ => out.word(word).unwrap(),
+        }
+    }
+}
+
+fn ordinal_word(word: &str) -> Option<&'static str> {
+    let ordinal = match word {
+        "zero" => "zeroth",
+        "one" => "first",
+        "two" => "second",
+        "three" => "third",
+        "four" => "fourth",
+        "five" => "fifth",
+        "six" => "sixth",
+        "seven" => "seventh",
+        "eight" => "eighth",
+        "nine" => "ninth",
+        "ten" => "tenth",
+        "eleven" => "eleventh",
+        "twelve" => "twelfth",
+        "thirteen" => "thirteenth",
+        "fourteen" => "fourteenth",
+        "fifteen" => "fifteenth",
+        "sixteen" => "sixteenth",
+        "seventeen" => "seventeenth",
+        "eighteen" => "eighteenth",
+        "nineteen" => "nineteenth",
+        "twenty" => "twentieth",
+        "thirty" => "thirtieth",
+        "forty" => "fortieth",
+        "fifty" => "fiftieth",
+        "sixty" => "sixtieth",
+        "seventy" => "seventieth",
+        "eighty" => "eightieth",
+        "ninety" => "ninetieth",
+        "hundred" => "hundredth",
+        "thousand" => "thousandth",
+        "million" => "millionth",
+        "billion" => "billionth",
+        "trillion" => "trillionth",
+        "quadrillion" => "quadrillionth",
+        _ => return None,
+    };
+    Some(ordinal)
+}
+
+/// Returns the numeric ordinal suffix "st", "nd", "rd", or "th".
+///
+/// Numbers ending in 11, 12, and 13 use "th".
+///
+/// # Example
+/// ```
+/// use shortscale::ordinal_suffix;
+///
+/// assert_eq!(ordinal_suffix(1), "st");
+/// assert_eq!(ordinal_suffix(12), "th");
+/// assert_eq!(ordinal_suffix(22), "nd");
+/// assert_eq!(ordinal_suffix(113), "th");
+/// ```
+pub fn ordinal_suffix(num: u64) -> &'static str {
+    match (num % 10, num % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Returns String with the number and its ordinal suffix, e.g. "22nd".
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_ordinal_numeric;
+///
+/// assert_eq!(shortscale_ordinal_numeric(113), "113th");
+/// ```
+pub fn shortscale_ordinal_numeric(num: u64) -> String {
+    format!("{}{}", num, ordinal_suffix(num))
+}
@@ -0,0 +1,46 @@
+//! Words padded with spaces to a fixed width, for tables.
+
+use crate::{shortscale_string_writer, Error};
+
+/// Alignment for [shortscale_padded](./fn.shortscale_padded.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    /// Spaces after the words.
+    #[default]
+    Left,
+    /// Spaces before the words.
+    Right,
+    /// Spaces on both sides, with the extra space after the words.
+    Center,
+}
+
+/// Returns words padded with spaces to a width in characters.
+///
+/// Returns [Error::Width](./enum.Error.html#variant.Width) when the words are wider.
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_padded, Align};
+///
+/// assert_eq!(shortscale_padded(42, 12, Align::Right).unwrap(), "   forty two");
+/// assert_eq!(shortscale_padded(42, 12, Align::Center).unwrap(), " forty two  ");
+/// assert!(shortscale_padded(420, 12, Align::Left).is_err());
+/// ```
+pub fn shortscale_padded(num: u64, width: usize, align: Align) -> Result<String, Error> {
+    let mut words = String::new();
+    shortscale_string_writer(&mut words, num);
+    let len = words.chars().count();
+    if len > width {
+        return Err(Error::Width { width, needed: len });
+    }
+    let (before, after) = match (align, width - len) {
+        (Align::Left, pad) => (0, pad),
+        (Align::Right, pad) => (pad, 0),
+        (Align::Center, pad) => (pad / 2, pad - pad / 2),
+    };
+    let mut s = String::with_capacity(width);
+    s.extend(std::iter::repeat(' ').take(before));
+    s.push_str(&words);
+    s.extend(std::iter::repeat(' ').take(after));
+    Ok(s)
+}
@@ -0,0 +1,52 @@
+//! Parallel conversion of many numbers, with the "rayon" feature.
+
+use crate::{shortscale, shortscale_string_writer};
+use rayon::prelude::*;
+
+/// Returns words for each number, converted in parallel on the rayon thread pool.  
+/// Requires the "rayon" feature.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_par_bulk;
+///
+/// let nums: Vec<u64> = (0..100_000).collect();
+/// let words = shortscale_par_bulk(&nums);
+/// assert_eq!(words[420], "four hundred and twenty");
+/// ```
+pub fn shortscale_par_bulk(nums: &[u64]) -> Vec<String> {
+    nums.par_iter().map(|num| shortscale(*num)).collect()
+}
+
+/// Calls `f` with the index and the words for each number, in parallel and in any order.
+///
+/// Words are written into one reusable buffer per thread, so only the callback decides
+/// what to allocate, e.g. to write a line into a file or to send a message on a channel.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_par_for_each;
+/// use std::sync::mpsc::channel;
+///
+/// let nums: Vec<u64> = (0..1_000).collect();
+/// let (sender, receiver) = channel();
+/// shortscale_par_for_each(&nums, |i, words| {
+///     if words.contains("seven") {
+///         sender.send(i).unwrap();
+///     }
+/// });
+/// drop(sender);
+/// assert_eq!(receiver.iter().count(), 271);
+/// ```
+pub fn shortscale_par_for_each<F>(nums: &[u64], f: F)
+where
+    F: Fn(usize, &str) + Sync + Send,
+{
+    nums.par_iter()
+        .enumerate()
+        .for_each_init(String::new, |buf, (i, num)| {
+            buf.clear();
+            shortscale_string_writer(buf, *num);
+            f(i, buf);
+        });
+}
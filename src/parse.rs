@@ -0,0 +1,166 @@
+//! Reverse direction, converts English words into numbers.
+
+use crate::{map, Error, ZeroWord};
+
+/// Returns the number given English words.
+///
+/// Accepts the output of shortscale, with or without "and", hyphens,
+/// or commas, and ignores ASCII case. 0 may also be "nought", "nil", or "oh".
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_parse;
+///
+/// assert_eq!(
+///     shortscale_parse(
+///         "four hundred and twenty billion nine hundred \
+///         and ninety nine thousand and fifteen"
+///     ),
+///     Ok(420_000_999_015)
+/// );
+/// assert_eq!(shortscale_parse("Twenty-Two"), Ok(22));
+/// ```
+pub fn shortscale_parse(words: &str) -> Result<u64, Error> {
+    let mut total: u64 = 0;
+    let mut group: u64 = 0; // current group of 1 to 999
+    let mut stage = Stage::Start;
+    let mut last_scale: u64 = u64::MAX;
+    let mut after_and = false;
+    let mut count = 0;
+
+    for (offset, word) in split_words(words) {
+        count += 1;
+        let num = match lookup(word) {
+            Some(num) => num,
+            None if word.eq_ignore_ascii_case("and") => {
+                // "and" only before tens and units
+                let after_scale = total > 0 && stage == Stage::Start;
+                if after_and || !(stage == Stage::Hundreds || after_scale) {
+                    return Err(unexpected(word, offset));
+                }
+                after_and = true;
+                continue;
+            }
+            None => {
+                return Err(Error::UnknownWord {
+                    word: word.to_string(),
+                    offset,
+                })
+            }
+        };
+        stage = match (num, stage) {
+            (0, Stage::Start) if count == 1 => Stage::Zero,
+            (1..=9, Stage::Start) if !after_and => Stage::Units,
+            (1..=9, Stage::Start) | (1..=9, Stage::Hundreds) | (1..=9, Stage::Tens) => Stage::Done,
+            (10..=19, Stage::Start) | (10..=19, Stage::Hundreds) => Stage::Done,
+            (20..=90, Stage::Start) | (20..=90, Stage::Hundreds) => Stage::Tens,
+            (100, Stage::Units) => Stage::Hundreds,
+            (1_000..=MAX_SCALE, Stage::Units)
+            | (1_000..=MAX_SCALE, Stage::Hundreds)
+            | (1_000..=MAX_SCALE, Stage::Tens)
+            | (1_000..=MAX_SCALE, Stage::Done)
+                if num < last_scale && !after_and =>
+            {
+                Stage::Start
+            }
+            _ => return Err(unexpected(word, offset)),
+        };
+        after_and = false;
+        match num {
+            100 => group *= 100,
+            1_000..=MAX_SCALE => {
+                total += group * num;
+                group = 0;
+                last_scale = num;
+            }
+            _ => group += num,
+        }
+    }
+
+    match (count, after_and) {
+        (0, _) => Err(Error::EmptyInput),
+        (_, true) => Err(Error::UnexpectedEnd),
+        _ => Ok(total + group),
+    }
+}
+
+// position within a group of 1 to 999
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Start,
+    Units, // 1 to 9 which may be followed by "hundred"
+    Hundreds,
+    Tens,
+    Done,
+    Zero, // nothing may follow "zero"
+}
+
+// words with byte offsets, separated by whitespace, hyphens, or commas
+fn split_words(words: &str) -> impl Iterator<Item = (usize, &str)> {
+    let start = words.as_ptr() as usize;
+    words
+        .split(|ch: char| ch.is_whitespace() || ch == '-' || ch == ',')
+        .filter(|word| !word.is_empty())
+        .map(move |word| (word.as_ptr() as usize - start, word))
+}
+
+fn lookup(word: &str) -> Option<u64> {
+    let zero = [ZeroWord::Nought, ZeroWord::Nil, ZeroWord::Oh];
+    if zero
+        .iter()
+        .any(|zero| zero.as_str().eq_ignore_ascii_case(word))
+    {
+        return Some(0);
+    }
+    WORD_VALUES
+        .iter()
+        .copied()
+        .find(|num| map(*num).eq_ignore_ascii_case(word))
+}
+
+fn unexpected(word: &str, offset: usize) -> Error {
+    Error::UnexpectedWord {
+        word: word.to_string(),
+        offset,
+    }
+}
+
+const MAX_SCALE: u64 = 1_000_000_000_000_000;
+
+// numbers with a word in map
+pub(crate) const WORD_VALUES: [u64; 34] = [
+    0,
+    1,
+    2,
+    3,
+    4,
+    5,
+    6,
+    7,
+    8,
+    9,
+    10,
+    11,
+    12,
+    13,
+    14,
+    15,
+    16,
+    17,
+    18,
+    19,
+    20,
+    30,
+    40,
+    50,
+    60,
+    70,
+    80,
+    90,
+    100,
+    1_000,
+    1_000_000,
+    1_000_000_000,
+    1_000_000_000_000,
+    1_000_000_000_000_000,
+];
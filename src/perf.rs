@@ -0,0 +1,91 @@
+//! Timing checks for performance regressions in CI, requires the "perf-test" feature.
+//!
+//! Timings depend on the machine and the build profile,
+//! so thresholds should be generous and checks should run with `--release`.
+//! Use the criterion benchmarks in this repository to compare implementations.
+//!
+//! # Example
+//! ```no_run
+//! use shortscale::perf::max_ns_per_conversion;
+//!
+//! assert!(max_ns_per_conversion() < 10_000.0);
+//! ```
+
+use crate::locale::EnUs;
+use crate::{
+    shortscale, shortscale_locale_write, shortscale_ordinal_string_writer, shortscale_parse,
+    shortscale_string_writer, NumWords, Options, MAX,
+};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Average time for one conversion with a public API.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub name: &'static str,
+    pub ns_per_conversion: f64,
+}
+
+// each conversion gets a number and its words, and writes into a reused String
+type Conversion = fn(&mut String, u64, &str);
+
+const CONVERSIONS: [(&str, Conversion); 5] = [
+    ("shortscale_string_writer", |s, num, _| {
+        shortscale_string_writer(s, num)
+    }),
+    ("NumWords", |s, num, _| {
+        write!(s, "{}", NumWords::new(num)).unwrap()
+    }),
+    ("shortscale_ordinal_string_writer", |s, num, _| {
+        shortscale_ordinal_string_writer(s, num)
+    }),
+    ("shortscale_parse", |_, _, words| {
+        black_box(shortscale_parse(words).unwrap());
+    }),
+    ("shortscale_locale_write", |s, num, _| {
+        shortscale_locale_write(s, num, &EnUs, &Options::new()).unwrap()
+    }),
+];
+
+const ROUNDS: usize = 200;
+
+/// Returns a Measurement for each API, converting numbers of all magnitudes
+/// the given number of rounds after one round to warm up.
+pub fn measure(rounds: usize) -> Vec<Measurement> {
+    let nums: Vec<u64> = (0..64)
+        .map(|i| 0x2545_f491_4f6c_dd1d_u64 >> i)
+        .filter(|num| *num <= MAX)
+        .collect();
+    let words: Vec<String> = nums.iter().map(|num| shortscale(*num)).collect();
+    let mut buf = String::with_capacity(crate::MAX_LEN);
+    let mut round = |convert: Conversion| {
+        for (num, words) in nums.iter().zip(words.iter()) {
+            buf.clear();
+            convert(&mut buf, black_box(*num), black_box(words));
+        }
+    };
+    CONVERSIONS
+        .iter()
+        .map(|(name, convert)| {
+            round(*convert);
+            let start = Instant::now();
+            for _ in 0..rounds {
+                round(*convert);
+            }
+            let conversions = (rounds.max(1) * nums.len()) as f64;
+            Measurement {
+                name,
+                ns_per_conversion: start.elapsed().as_nanos() as f64 / conversions,
+            }
+        })
+        .collect()
+}
+
+/// Returns the average nanoseconds per conversion for the slowest API.
+pub fn max_ns_per_conversion() -> f64 {
+    measure(ROUNDS)
+        .iter()
+        .map(|m| m.ns_per_conversion)
+        .fold(0.0, f64::max)
+}
@@ -0,0 +1,22 @@
+//! Seeded random numbers for test samples and dictation exercises.
+
+// xorshift64*, the same numbers for the same seed
+#[derive(Debug, Clone)]
+pub(crate) struct XorShift(u64);
+
+impl XorShift {
+    pub(crate) fn new(seed: u64) -> Self {
+        // the state must not be 0
+        match seed ^ 0x9e37_79b9_7f4a_7c15 {
+            0 => XorShift(1),
+            state => XorShift(state),
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+}
@@ -0,0 +1,96 @@
+//! Roman numerals from I to MMMCMXCIX (1 to 3999).
+//!
+//! # Example
+//! ```
+//! use shortscale::roman::{from_roman, to_roman};
+//!
+//! assert_eq!(to_roman(2024).unwrap(), "MMXXIV");
+//! assert_eq!(from_roman("MMXXIV"), Ok(2024));
+//! ```
+
+use crate::Error;
+
+const NUMERALS: [(u64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+const MIN: u64 = 1;
+const MAX: u64 = 3999;
+
+/// Returns the Roman numeral for numbers from 1 to 3999.
+pub fn to_roman(num: u64) -> Result<String, Error> {
+    if !(MIN..=MAX).contains(&num) {
+        return Err(Error::OutOfRange { min: MIN, max: MAX });
+    }
+    let mut s = String::new();
+    let mut num = num;
+    for (value, numeral) in NUMERALS.iter() {
+        while num >= *value {
+            s.push_str(numeral);
+            num -= value;
+        }
+    }
+    Ok(s)
+}
+
+/// Returns the number given a Roman numeral, ignoring ASCII case.
+///
+/// Only standard subtractive forms are accepted, e.g. "IV" but not "IIII".
+pub fn from_roman(numeral: &str) -> Result<u64, Error> {
+    if numeral.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+    let mut values = Vec::with_capacity(numeral.len());
+    for (offset, ch) in numeral.char_indices() {
+        match value(ch) {
+            Some(v) => values.push(v),
+            None => return Err(Error::InvalidChar { ch, offset }),
+        }
+    }
+    let mut num: i64 = 0;
+    for (i, v) in values.iter().enumerate() {
+        match values.get(i + 1) {
+            Some(next) if next > v => num -= v,
+            _ => num += v,
+        }
+    }
+
+    // reject non-standard forms by comparing with the canonical numeral
+    let num = num.max(0) as u64;
+    let canonical = to_roman(num)?;
+    let mut expected = canonical.chars();
+    for (offset, ch) in numeral.char_indices() {
+        if expected.next() != Some(ch.to_ascii_uppercase()) {
+            return Err(Error::InvalidChar { ch, offset });
+        }
+    }
+    match expected.next() {
+        Some(_) => Err(Error::UnexpectedEnd),
+        None => Ok(num),
+    }
+}
+
+fn value(ch: char) -> Option<i64> {
+    match ch.to_ascii_uppercase() {
+        'I' => Some(1),
+        'V' => Some(5),
+        'X' => Some(10),
+        'L' => Some(50),
+        'C' => Some(100),
+        'D' => Some(500),
+        'M' => Some(1000),
+        _ => None,
+    }
+}
@@ -0,0 +1,75 @@
+//! Serialize numbers as English words, and deserialize words back into numbers.
+//! Requires the "serde" feature.
+//!
+//! Use the [Words](./struct.Words.html) newtype, or this module with `#[serde(with)]`.
+//!
+//! # Example
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "shortscale::serde_words")]
+//!     retries: u64,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{"retries":"twenty two"}"#).unwrap();
+//! assert_eq!(config.retries, 22);
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"retries":"twenty two"}"#
+//! );
+//! ```
+
+use crate::{shortscale, shortscale_parse};
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Number which serializes as English words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Words(pub u64);
+
+impl Serialize for Words {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Words {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Words)
+    }
+}
+
+/// Serialize a number as English words.
+///
+/// Numbers larger than 999_999_999_999_999_999 return an error
+/// since "(big number)" cannot be deserialized.
+pub fn serialize<S: Serializer>(num: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+    if *num > crate::MAX {
+        return Err(serde::ser::Error::custom(crate::Error::out_of_range()));
+    }
+    serializer.serialize_str(&shortscale(*num))
+}
+
+/// Deserialize English words into a number.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    deserializer.deserialize_str(WordsVisitor)
+}
+
+struct WordsVisitor;
+
+impl<'de> Visitor<'de> for WordsVisitor {
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a number in English words")
+    }
+
+    fn visit_str<E: de::Error>(self, words: &str) -> Result<u64, E> {
+        shortscale_parse(words).map_err(E::custom)
+    }
+}
@@ -12,6 +12,13 @@
 //!
 //! Copyright 2021, Jürgen Leschner - github.com/jldec - MIT license
 
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+pub mod locale;
+use locale::{EnGb, EnUs, Locale};
+
 /// Returns String with words given an unsigned integer.
 ///
 /// Supports positive integers from 0 to 999_999_999_999_999_999.  
@@ -27,8 +34,9 @@
 ///     and ninety nine thousand and fifteen"
 ///     );
 /// ```
+#[allow(clippy::needless_return)]
 pub fn shortscale(num: u64) -> String {
-    let mut s = String::new();
+    let mut s = String::with_capacity(shortscale_len(num));
     shortscale_string_writer(&mut s, num);
     return s;
 }
@@ -44,73 +52,246 @@ pub fn shortscale(num: u64) -> String {
 /// assert_eq!(my_string, "The number 27 in words is twenty seven");
 /// ```
 pub fn shortscale_string_writer(s: &mut String, num: u64) {
-    // simple lookup in map
-    if num <= 20 || num > 999_999_999_999_999_999 {
-        s.push_str(map(num));
-        return;
-    }
-    let mut len: usize = 0; // mutated by push_words
-    push_scale(s, &mut len, num, 1_000_000_000_000_000); // quadrillions
-    push_scale(s, &mut len, num, 1_000_000_000_000); // trillions
-    push_scale(s, &mut len, num, 1_000_000_000); // billions
-    push_scale(s, &mut len, num, 1_000_000); // millions
-    push_scale(s, &mut len, num, 1_000); // thousands
-    push_hundreds(s, &mut len, num);
-    let and_word: bool = len > 0;
-    push_tens_and_units(s, &mut len, num, and_word);
+    // writing into a String never fails
+    shortscale_write(s, num).unwrap();
 }
 
-fn push_word(s: &mut String, len: &mut usize, word: &str) {
-    if *len > 0 {
-        s.push_str(" ");
-        *len += " ".len();
+/// Returns an Iterator with words for each number.
+///
+/// Words are written into one internal buffer, and each String is copied
+/// from it with an exact capacity, so there is a single allocation per number.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_iter;
+///
+/// let words: Vec<String> = shortscale_iter(vec![1, 20, 300]).collect();
+/// assert_eq!(words, ["one", "twenty", "three hundred"]);
+/// ```
+pub fn shortscale_iter<I: IntoIterator<Item = u64>>(nums: I) -> impl Iterator<Item = String> {
+    let mut buf = String::new();
+    nums.into_iter().map(move |num| {
+        buf.clear();
+        shortscale_string_writer(&mut buf, num);
+        buf.as_str().to_owned()
+    })
+}
+
+/// Writes words for each number into mutable String, joined by a separator.
+///
+/// There are no allocations when the String has enough capacity.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_extend;
+///
+/// let mut report = String::from("Totals: ");
+/// shortscale_extend(&mut report, 1..=3, ", ");
+/// assert_eq!(report, "Totals: one, two, three");
+/// ```
+pub fn shortscale_extend<I: IntoIterator<Item = u64>>(s: &mut String, nums: I, separator: &str) {
+    for (i, num) in nums.into_iter().enumerate() {
+        if i > 0 {
+            s.push_str(separator);
+        }
+        shortscale_string_writer(s, num);
     }
-    s.push_str(word);
-    *len += word.len();
 }
 
-fn push_tens_and_units(s: &mut String, len: &mut usize, num: u64, and_word: bool) {
-    let num = num % 100;
-    if num == 0 {
-        return;
+/// Same as shortscale but writes words into any [fmt::Write](https://doc.rust-lang.org/std/fmt/trait.Write.html)
+/// e.g. a String or a Formatter.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_write;
+/// use std::fmt;
+///
+/// struct Price(u64);
+///
+/// impl fmt::Display for Price {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         shortscale_write(f, self.0)?;
+///         f.write_str(" dollars")
+///     }
+/// }
+///
+/// assert_eq!(Price(27).to_string(), "twenty seven dollars");
+/// ```
+pub fn shortscale_write<W: fmt::Write>(w: &mut W, num: u64) -> fmt::Result {
+    table::write_table(w, num)
+}
+
+/// Same as shortscale but with [Options](./struct.Options.html).
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_with, Options, Style};
+///
+/// assert_eq!(
+///     shortscale_with(420_000_999_015, &Options::new().style(Style::US).hyphenate(true)),
+///     "four hundred twenty billion nine hundred ninety-nine thousand fifteen"
+/// );
+/// ```
+pub fn shortscale_with(num: u64, options: &Options) -> String {
+    let mut s = String::new();
+    // writing into a String never fails
+    shortscale_write_with(&mut s, num, options).unwrap();
+    s
+}
+
+/// Same as shortscale_write but with [Options](./struct.Options.html).
+pub fn shortscale_write_with<W: fmt::Write>(w: &mut W, num: u64, options: &Options) -> fmt::Result {
+    if *options == Options::new() {
+        return table::write_table(w, num);
     }
-    if and_word {
-        push_word(s, len, "and");
+    match options.style {
+        Style::GB => locale::write_locale(w, num, &EnGb, options),
+        Style::US => locale::write_locale(w, num, &EnUs, options),
     }
-    match num {
-        1..=20 => push_word(s, len, map(num)),
-        _ => {
-            push_word(s, len, map(num / 10 * 10));
-            let num = num % 10;
-            match num {
-                0 => (),
-                _ => push_word(s, len, map(num)),
-            };
-        }
-    };
 }
 
-fn push_hundreds(s: &mut String, len: &mut usize, num: u64) {
-    let num = num / 100 % 10;
-    if num == 0 {
-        return;
+/// Returns String with words given an unsigned integer and a [Locale](./locale/trait.Locale.html).
+///
+/// # Example
+/// ```
+/// use shortscale::locale::EnUs;
+/// use shortscale::shortscale_locale;
+///
+/// assert_eq!(shortscale_locale(101, &EnUs), "one hundred one");
+/// ```
+pub fn shortscale_locale<L: Locale>(num: u64, locale: &L) -> String {
+    let mut s = String::new();
+    // writing into a String never fails
+    shortscale_locale_write(&mut s, num, locale, &Options::new()).unwrap();
+    s
+}
+
+/// Same as shortscale_locale but writes words into any fmt::Write, with [Options](./struct.Options.html).
+///
+/// The Options style is ignored, since the locale decides the language.
+pub fn shortscale_locale_write<W: fmt::Write, L: Locale>(
+    w: &mut W,
+    num: u64,
+    locale: &L,
+    options: &Options,
+) -> fmt::Result {
+    locale::write_locale(w, num, locale, options)
+}
+
+/// Returns String with words using the Indian numbering system with lakh and crore.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_indian;
+///
+/// assert_eq!(
+///     shortscale_indian(12_345_678),
+///     "one crore twenty three lakh forty five thousand six hundred and seventy eight"
+/// );
+/// ```
+pub fn shortscale_indian(num: u64) -> String {
+    shortscale_locale(num, &locale::EnIn)
+}
+
+/// Returns String with Japanese kanji numerals, grouped by myriads (10^4).  
+/// Requires the "ja" feature.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_ja;
+///
+/// assert_eq!(shortscale_ja(123_456_789), "一億二千三百四十五万六千七百八十九");
+/// ```
+#[cfg(feature = "ja")]
+pub fn shortscale_ja(num: u64) -> String {
+    shortscale_locale(num, &locale::Ja)
+}
+
+/// Same as shortscale but writes words into any [io::Write](https://doc.rust-lang.org/std/io/trait.Write.html)
+/// e.g. a File, a TcpStream, or Stdout.
+///
+/// Each word is written separately, so wrap unbuffered sinks in a BufWriter.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_io_write;
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// shortscale_io_write(&mut out, 27).unwrap();
+/// assert_eq!(out, b"twenty seven");
+/// ```
+pub fn shortscale_io_write<W: io::Write>(w: &mut W, num: u64) -> io::Result<()> {
+    let mut adapter = IoAdapter {
+        inner: w,
+        error: Ok(()),
+    };
+    match shortscale_write(&mut adapter, num) {
+        Ok(()) => Ok(()),
+        Err(_) => adapter.error,
     }
-    push_word(s, len, map(num));
-    push_word(s, len, map(100))
 }
 
-fn push_scale(s: &mut String, len: &mut usize, num: u64, thousands: u64) {
-    let num = num / thousands % 1_000;
-    if num == 0 {
-        return;
+// forwards fmt::Write calls to io::Write, keeping the io::Error
+struct IoAdapter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: io::Result<()>,
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Err(e);
+            fmt::Error
+        })
     }
-    push_hundreds(s, len, num);
-    let and_word: bool = (num / 100 % 10) > 0;
-    push_tens_and_units(s, len, num, and_word);
-    push_word(s, len, map(thousands));
 }
 
-fn map(num: u64) -> &'static str {
+// largest number with words
+const MAX: u64 = 999_999_999_999_999_999;
+
+/// Largest length in bytes of words from shortscale with the default Options,
+/// e.g. for 777_777_777_777_777_777.
+pub const MAX_LEN: usize = 237;
+
+const SCALES: [(u64, &str); 5] = [
+    (1_000, map(1_000)),
+    (1_000_000, map(1_000_000)),
+    (1_000_000_000, map(1_000_000_000)),
+    (1_000_000_000_000, map(1_000_000_000_000)),
+    (1_000_000_000_000_000, map(1_000_000_000_000_000)),
+];
+
+/// Returns the scale values and words from thousand to quadrillion, in ascending order.
+///
+/// # Example
+/// ```
+/// use shortscale::scales;
+///
+/// assert_eq!(scales()[0], (1_000, "thousand"));
+/// assert_eq!(scales().last(), Some(&(1_000_000_000_000_000, "quadrillion")));
+/// ```
+pub fn scales() -> &'static [(u64, &'static str)] {
+    &SCALES
+}
+
+/// Returns the scale word for a power of ten, e.g. "million" for 6.
+///
+/// # Example
+/// ```
+/// use shortscale::scale_name;
+///
+/// assert_eq!(scale_name(6), Some("million"));
+/// assert_eq!(scale_name(7), None);
+/// ```
+pub fn scale_name(power_of_ten: u32) -> Option<&'static str> {
+    let value = 10_u64.checked_pow(power_of_ten)?;
+    SCALES
+        .iter()
+        .find(|(scale, _)| *scale == value)
+        .map(|(_, name)| *name)
+}
+
+const fn map(num: u64) -> &'static str {
     match num {
         0 => "zero",
         1 => "one",
@@ -150,5 +331,441 @@ fn map(num: u64) -> &'static str {
     }
 }
 
+// same as map, with the zero word option
+fn map_with(num: u64, options: &Options) -> &'static str {
+    match num {
+        0 => options.zero.as_str(),
+        _ => map(num),
+    }
+}
+
+/// English style for writing numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// British English with "and" before tens and units, e.g. "one hundred and one".
+    #[default]
+    GB,
+    /// American English without "and", e.g. "one hundred one".
+    US,
+}
+
+/// Parses a locale name, "en-GB" or "gb" for GB and "en-US" or "us" for US, ignoring ASCII case.
+///
+/// # Example
+/// ```
+/// use shortscale::{Error, Style};
+///
+/// assert_eq!("en-US".parse(), Ok(Style::US));
+/// assert_eq!(
+///     "fr".parse::<Style>(),
+///     Err(Error::UnsupportedLocale { name: "fr".to_string() })
+/// );
+/// ```
+impl FromStr for Style {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s.to_ascii_lowercase().replace('_', "-").as_str() {
+            "gb" | "en-gb" => Ok(Style::GB),
+            "us" | "en-us" => Ok(Style::US),
+            _ => Err(Error::UnsupportedLocale {
+                name: s.to_string(),
+            }),
+        }
+    }
+}
+
+/// Letter case for writing words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+    /// "four hundred and twenty"
+    #[default]
+    Lower,
+    /// "Four Hundred and Twenty", with a lowercase "and".
+    Title,
+    /// "FOUR HUNDRED AND TWENTY"
+    Upper,
+    /// "Four hundred and twenty"
+    Sentence,
+}
+
+/// Word for 0 in English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZeroWord {
+    #[default]
+    Zero,
+    /// British, e.g. in mathematics.
+    Nought,
+    /// British, e.g. in sports scores.
+    Nil,
+    /// Spoken like the letter O, e.g. in phone numbers.
+    Oh,
+}
+
+impl ZeroWord {
+    /// Returns the word, e.g. "nought".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZeroWord::Zero => "zero",
+            ZeroWord::Nought => "nought",
+            ZeroWord::Nil => "nil",
+            ZeroWord::Oh => "oh",
+        }
+    }
+}
+
+/// Options for writing words.
+///
+/// The default is Style::GB in lower case without hyphens, which is the same as shortscale.
+///
+/// # Example
+/// ```
+/// use shortscale::{Options, Style};
+///
+/// let options = Options::new().style(Style::US).hyphenate(true);
+/// ```
+///
+/// ```
+/// use shortscale::{shortscale_with, Case, Options};
+///
+/// let options = Options::new().case(Case::Title).hyphenate(true);
+/// assert_eq!(shortscale_with(421, &options), "Four Hundred and Twenty-One");
+///
+/// let options = Options::new().separator("-");
+/// assert_eq!(shortscale_with(420, &options), "four-hundred-and-twenty");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Options {
+    pub style: Style,
+    pub hyphenate: bool,
+    pub case: Case,
+    pub zero: ZeroWord,
+    pub separator: &'static str,
+    pub conjunction: &'static str,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            style: Style::default(),
+            hyphenate: false,
+            case: Case::default(),
+            zero: ZeroWord::default(),
+            separator: " ",
+            conjunction: "and",
+        }
+    }
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the English style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Join tens and units with a hyphen, e.g. "twenty-two".
+    pub fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.hyphenate = hyphenate;
+        self
+    }
+
+    /// Set the letter case.
+    pub fn case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Set the word for 0, e.g. "nought".
+    pub fn zero(mut self, zero: ZeroWord) -> Self {
+        self.zero = zero;
+        self
+    }
+
+    /// Set the separator between words, " " by default, e.g. "-" for "four-hundred-and-twenty".
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set the conjunction before tens and units in Style::GB, "and" by default.
+    /// An empty conjunction is left out.
+    pub fn conjunction(mut self, conjunction: &'static str) -> Self {
+        self.conjunction = conjunction;
+        self
+    }
+}
+
+/// Display trait implementation for numbers with [Options](./struct.Options.html),  
+/// for use with format!(), println!(), write!(), or to_string().
+///
+/// # Example
+/// ```
+/// use shortscale::{NumWords, Style};
+///
+/// let words = NumWords::new(420_000_999_015).style(Style::US).hyphenate(true);
+/// assert_eq!(
+///     format!("{} dollars", words),
+///     "four hundred twenty billion nine hundred ninety-nine thousand fifteen dollars"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumWords {
+    n: u64,
+    options: Options,
+}
+
+impl NumWords {
+    pub fn new(n: u64) -> Self {
+        Self {
+            n,
+            options: Options::new(),
+        }
+    }
+
+    /// Returns the number.
+    pub fn number(&self) -> u64 {
+        self.n
+    }
+
+    /// Replace all options.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the English style.
+    pub fn style(mut self, style: Style) -> Self {
+        self.options = self.options.style(style);
+        self
+    }
+
+    /// Join tens and units with a hyphen, e.g. "twenty-two".
+    pub fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.options = self.options.hyphenate(hyphenate);
+        self
+    }
+
+    /// Set the letter case.
+    pub fn case(mut self, case: Case) -> Self {
+        self.options = self.options.case(case);
+        self
+    }
+
+    /// Set the word for 0, e.g. "nought".
+    pub fn zero(mut self, zero: ZeroWord) -> Self {
+        self.options = self.options.zero(zero);
+        self
+    }
+
+    /// Set the separator between words, " " by default.
+    pub fn separator(mut self, separator: &'static str) -> Self {
+        self.options = self.options.separator(separator);
+        self
+    }
+
+    /// Set the conjunction before tens and units in Style::GB, "and" by default.
+    pub fn conjunction(mut self, conjunction: &'static str) -> Self {
+        self.options = self.options.conjunction(conjunction);
+        self
+    }
+}
+
+/// Formatting flags for width, fill, alignment, and precision are applied to the words,
+/// e.g. `format!("{:>40}", NumWords::new(42))`.
+impl fmt::Display for NumWords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return shortscale_write_with(f, self.n, &self.options);
+        }
+        let mut s = String::new();
+        shortscale_write_with(&mut s, self.n, &self.options)?;
+        f.pad(&s)
+    }
+}
+
+/// Error returned by the fallible conversions in this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input was empty.
+    EmptyInput,
+    /// Unexpected character at byte offset.
+    InvalidChar { ch: char, offset: usize },
+    /// The number is outside the supported range, e.g. 0 to 999_999_999_999_999_999.
+    OutOfRange { min: u64, max: u64 },
+    /// Word which is not a number word, at byte offset.
+    UnknownWord { word: String, offset: usize },
+    /// Number word in the wrong place, at byte offset.
+    UnexpectedWord { word: String, offset: usize },
+    /// The input ended with an incomplete number, e.g. "one thousand and".
+    UnexpectedEnd,
+    /// The text needs more characters than the line width.
+    Width { width: usize, needed: usize },
+    /// Locale name which is not supported, e.g. "fr".
+    UnsupportedLocale { name: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EmptyInput => write!(f, "empty input"),
+            Error::InvalidChar { ch, offset } => {
+                write!(f, "invalid character {:?} at offset {}", ch, offset)
+            }
+            Error::OutOfRange { min, max } => {
+                write!(f, "number out of range {} to {}", min, max)
+            }
+            Error::UnknownWord { word, offset } => {
+                write!(f, "unknown word {:?} at offset {}", word, offset)
+            }
+            Error::UnexpectedWord { word, offset } => {
+                write!(f, "unexpected word {:?} at offset {}", word, offset)
+            }
+            Error::UnexpectedEnd => write!(f, "unexpected end of input"),
+            Error::Width { width, needed } => {
+                write!(
+                    f,
+                    "text needs {} characters, more than the width {}",
+                    needed, width
+                )
+            }
+            Error::UnsupportedLocale { name } => write!(f, "unsupported locale {:?}", name),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    // the range of numbers with words
+    pub(crate) fn out_of_range() -> Self {
+        Error::OutOfRange { min: 0, max: MAX }
+    }
+}
+
+mod table;
+
+mod rng;
+
+mod decimal;
+pub use decimal::{shortscale_decimal, DecimalStyle};
+
+mod ordinal;
+pub use ordinal::{
+    ordinal_suffix, shortscale_ordinal, shortscale_ordinal_numeric,
+    shortscale_ordinal_string_writer, shortscale_ordinal_with,
+};
+
+mod year;
+pub use year::shortscale_year;
+
+mod digits;
+pub use digits::{shortscale_digits, shortscale_grouped};
+
+mod parse;
+pub use parse::shortscale_parse;
+
+mod words;
+pub use words::shortscale_words;
+
+mod slug;
+pub use slug::{parse_slug, shortscale_slug};
+
+mod words_ref;
+pub use words_ref::{shortscale_ref, WordsRef};
+
+mod len;
+pub use len::shortscale_len;
+
+mod tokens;
+pub use tokens::{shortscale_tokens, shortscale_tokens_with, Token};
+
+mod stress;
+pub use stress::{pronunciation, shortscale_annotate, shortscale_stressed, Pronunciation};
+
+mod ssml;
+pub use ssml::{shortscale_ssml, SsmlOptions};
+
+mod approx;
+pub use approx::{shortscale_approx, shortscale_approx_with, ApproxOptions, Prefix, Rounding};
+
+mod compact;
+pub use compact::shortscale_compact;
+
+mod fraction;
+pub use fraction::shortscale_fraction;
+
+mod count;
+pub use count::{count_of, count_of_numeric};
+
+mod arith;
+
+mod list;
+pub use list::{join_words_list, ListOptions};
+
+mod time;
+pub use time::{shortscale_clock, shortscale_clock_with, shortscale_duration, ClockStyle};
+
+mod padded;
+pub use padded::{shortscale_padded, Align};
+
+mod cheque;
+pub use cheque::{shortscale_cheque, ChequeOptions};
+
+mod big;
+pub use big::{shortscale_bigint, shortscale_bigint_with};
+
+mod to_words;
+pub use to_words::ToWords;
+
+mod const_words;
+pub use const_words::{shortscale_const, ConstWords};
+
+#[cfg(feature = "bigint")]
+mod biguint;
+#[cfg(feature = "bigint")]
+pub use biguint::{
+    shortscale_for, shortscale_for_with, shortscale_for_write, shortscale_ordinal_for,
+    shortscale_ordinal_for_with,
+};
+
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "rayon")]
+pub use par::{shortscale_par_bulk, shortscale_par_for_each};
+
+#[cfg(feature = "smallstring")]
+mod small;
+#[cfg(feature = "smallstring")]
+pub use small::{shortscale_small, SmallWords};
+
+pub mod roman;
+
+pub mod dictation;
+
+#[cfg(feature = "serde")]
+pub mod serde_words;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "js_compat")]
+pub mod js_compat;
+
+#[cfg(feature = "capi")]
+pub mod ffi;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "perf-test")]
+pub mod perf;
+
 #[cfg(any(extra, doc))]
 pub mod extra;
@@ -0,0 +1,104 @@
+//! URL and identifier safe words, e.g. "four_hundred_twenty".
+
+use crate::{shortscale_parse, Error, Options, Style, ToWords};
+
+/// Returns words in ASCII lower case separated by underscores, without "and".
+///
+/// Numbers larger than 999_999_999_999_999_999 continue with quintillion,
+/// like [ToWords](./trait.ToWords.html).
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_slug;
+///
+/// assert_eq!(
+///     shortscale_slug(420_000_999_015),
+///     "four_hundred_twenty_billion_nine_hundred_ninety_nine_thousand_fifteen"
+/// );
+/// ```
+pub fn shortscale_slug(num: u64) -> String {
+    num.to_words_with(&Options::new().style(Style::US).separator("_"))
+}
+
+/// Returns the number given a slug from [shortscale_slug](./fn.shortscale_slug.html).
+///
+/// Slugs for numbers up to u64::MAX may contain quintillion.
+/// Characters other than ASCII lower case letters and underscores return an error.
+///
+/// # Example
+/// ```
+/// use shortscale::{parse_slug, shortscale_slug};
+///
+/// assert_eq!(parse_slug("four_hundred_twenty"), Ok(420));
+/// assert_eq!(parse_slug(&shortscale_slug(u64::MAX)), Ok(u64::MAX));
+/// ```
+pub fn parse_slug(slug: &str) -> Result<u64, Error> {
+    if let Some((offset, ch)) = slug
+        .char_indices()
+        .find(|(_, ch)| !(ch.is_ascii_lowercase() || *ch == '_'))
+    {
+        return Err(Error::InvalidChar { ch, offset });
+    }
+    // same byte offsets in errors
+    let words = slug.replace('_', " ");
+    let offset = match find_word(&words, QUINTILLION) {
+        Some(offset) => offset,
+        None => return shortscale_parse(&words),
+    };
+    let high = match shortscale_parse(&words[..offset]) {
+        Ok(high) if high > 0 => high,
+        _ => return Err(unexpected(QUINTILLION, offset)),
+    };
+    let start = offset + QUINTILLION.len();
+    if let Some(again) = find_word(&words[start..], QUINTILLION) {
+        return Err(unexpected(QUINTILLION, start + again));
+    }
+    let low = match words[start..].trim() {
+        "" => 0,
+        // shortscale_slug never writes zero after quintillion
+        "zero" => return Err(unexpected("zero", start + 1)),
+        _ => shortscale_parse(&words[start..]).map_err(|e| shift(e, start))?,
+    };
+    high.checked_mul(1_000_000_000_000_000_000)
+        .and_then(|high| high.checked_add(low))
+        .ok_or(Error::OutOfRange {
+            min: 0,
+            max: u64::MAX,
+        })
+}
+
+const QUINTILLION: &str = "quintillion";
+
+// byte offset of a whole word
+fn find_word(words: &str, word: &str) -> Option<usize> {
+    let mut offset = 0;
+    for w in words.split(' ') {
+        if w == word {
+            return Some(offset);
+        }
+        offset += w.len() + 1;
+    }
+    None
+}
+
+fn unexpected(word: &str, offset: usize) -> Error {
+    Error::UnexpectedWord {
+        word: word.to_string(),
+        offset,
+    }
+}
+
+// byte offsets after quintillion are relative to the rest of the slug
+fn shift(error: Error, start: usize) -> Error {
+    match error {
+        Error::UnknownWord { word, offset } => Error::UnknownWord {
+            word,
+            offset: offset + start,
+        },
+        Error::UnexpectedWord { word, offset } => Error::UnexpectedWord {
+            word,
+            offset: offset + start,
+        },
+        error => error,
+    }
+}
@@ -0,0 +1,28 @@
+//! Words on the stack, with the "smallstring" feature.
+
+use crate::{shortscale_write, MAX_LEN};
+use smallstr::SmallString;
+
+/// String with inline capacity for the words of any number with the default Options.
+pub type SmallWords = SmallString<[u8; 240]>;
+
+const _: () = assert!(MAX_LEN <= 240);
+
+/// Same as shortscale but returns the words in a [SmallString](https://docs.rs/smallstr),
+/// which never allocates on the heap.  
+/// Requires the "smallstring" feature.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_small;
+///
+/// let words = shortscale_small(420);
+/// assert_eq!(words, "four hundred and twenty");
+/// assert!(!words.spilled());
+/// ```
+pub fn shortscale_small(num: u64) -> SmallWords {
+    let mut s = SmallWords::new();
+    // writing into a SmallString never fails
+    shortscale_write(&mut s, num).unwrap();
+    s
+}
@@ -0,0 +1,118 @@
+//! SSML markup for speech synthesis.
+
+use crate::locale::WordWriter;
+use crate::{shortscale_tokens_with, Options, Token, MAX};
+use std::fmt::Write;
+
+/// Options for [shortscale_ssml](./fn.shortscale_ssml.html).
+///
+/// The default is a 200ms pause after scale words, wrapped in a `<speak>` element.
+///
+/// # Example
+/// ```
+/// use shortscale::{Options, SsmlOptions, Style};
+///
+/// let options = SsmlOptions::new()
+///     .pause_ms(300)
+///     .speak(false)
+///     .options(Options::new().style(Style::US));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SsmlOptions {
+    pub pause_ms: u32,
+    pub speak: bool,
+    pub options: Options,
+}
+
+impl Default for SsmlOptions {
+    fn default() -> Self {
+        Self {
+            pause_ms: 200,
+            speak: true,
+            options: Options::new(),
+        }
+    }
+}
+
+impl SsmlOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the pause after thousand, million, and larger scale words, 0 for no pauses.
+    pub fn pause_ms(mut self, pause_ms: u32) -> Self {
+        self.pause_ms = pause_ms;
+        self
+    }
+
+    /// Wrap the fragment in a `<speak>` element.
+    pub fn speak(mut self, speak: bool) -> Self {
+        self.speak = speak;
+        self
+    }
+
+    /// Set the [Options](./struct.Options.html) for the words,
+    /// including the letter case, separator, and conjunction.
+    pub fn options(mut self, options: Options) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+/// Returns SSML given an unsigned integer, with a `<break>` after each scale word.
+///
+/// Numbers larger than 999_999_999_999_999_999 are passed to the speech engine
+/// as digits in a `<say-as interpret-as="cardinal">` element.
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_ssml, SsmlOptions};
+///
+/// assert_eq!(
+///     shortscale_ssml(2_020, &SsmlOptions::new()),
+///     r#"<speak>two thousand<break time="200ms"/> and twenty</speak>"#
+/// );
+/// ```
+pub fn shortscale_ssml(num: u64, ssml: &SsmlOptions) -> String {
+    let mut s = String::new();
+    if ssml.speak {
+        s.push_str("<speak>");
+    }
+    if num > MAX {
+        // writing into a String never fails
+        write!(s, r#"<say-as interpret-as="cardinal">{}</say-as>"#, num).unwrap();
+    } else {
+        write_tokens(&mut s, num, ssml);
+    }
+    if ssml.speak {
+        s.push_str("</speak>");
+    }
+    s
+}
+
+fn write_tokens(s: &mut String, num: u64, ssml: &SsmlOptions) {
+    let options = &ssml.options;
+    let mut out = WordWriter::new(s, options);
+    let mut pause = false;
+    let mut join = false;
+    for token in shortscale_tokens_with(num, options) {
+        // writing into a String never fails
+        if pause {
+            out.raw(&format!(r#"<break time="{}ms"/>"#, ssml.pause_ms))
+                .unwrap();
+            pause = false;
+        }
+        match token {
+            Token::Hyphen => out.join(token.as_str()),
+            Token::And => out.word(options.conjunction),
+            _ if join => out.join(token.as_str()),
+            _ => out.word(token.as_str()),
+        }
+        .unwrap();
+        join = token == Token::Hyphen;
+        if let Token::Scale(word) = token {
+            pause = ssml.pause_ms > 0 && word != "hundred";
+        }
+    }
+}
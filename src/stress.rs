@@ -0,0 +1,148 @@
+//! Syllables, stress, and IPA hints for number words, e.g. "four HUN-dred and TWEN-ty".
+
+use crate::{shortscale_tokens_with, Options, Token};
+
+/// Syllables, stressed syllable, and an approximate IPA transcription for a word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pronunciation {
+    pub word: &'static str,
+    pub syllables: &'static [&'static str],
+    /// Index of the stressed syllable.
+    pub stress: usize,
+    /// British English IPA, e.g. "ˈhʌndɹəd".
+    pub ipa: &'static str,
+}
+
+const fn p(
+    word: &'static str,
+    syllables: &'static [&'static str],
+    stress: usize,
+    ipa: &'static str,
+) -> Pronunciation {
+    Pronunciation {
+        word,
+        syllables,
+        stress,
+        ipa,
+    }
+}
+
+// one entry for each word from map, the zero words, and "and"
+static PRONUNCIATIONS: [Pronunciation; 38] = [
+    p("zero", &["ze", "ro"], 0, "ˈzɪəɹəʊ"),
+    p("one", &["one"], 0, "wʌn"),
+    p("two", &["two"], 0, "tuː"),
+    p("three", &["three"], 0, "θɹiː"),
+    p("four", &["four"], 0, "fɔː"),
+    p("five", &["five"], 0, "faɪv"),
+    p("six", &["six"], 0, "sɪks"),
+    p("seven", &["sev", "en"], 0, "ˈsɛvən"),
+    p("eight", &["eight"], 0, "eɪt"),
+    p("nine", &["nine"], 0, "naɪn"),
+    p("ten", &["ten"], 0, "tɛn"),
+    p("eleven", &["e", "lev", "en"], 1, "ɪˈlɛvən"),
+    p("twelve", &["twelve"], 0, "twɛlv"),
+    p("thirteen", &["thir", "teen"], 1, "θɜːˈtiːn"),
+    p("fourteen", &["four", "teen"], 1, "fɔːˈtiːn"),
+    p("fifteen", &["fif", "teen"], 1, "fɪfˈtiːn"),
+    p("sixteen", &["six", "teen"], 1, "sɪksˈtiːn"),
+    p("seventeen", &["sev", "en", "teen"], 2, "sɛvənˈtiːn"),
+    p("eighteen", &["eigh", "teen"], 1, "eɪˈtiːn"),
+    p("nineteen", &["nine", "teen"], 1, "naɪnˈtiːn"),
+    p("twenty", &["twen", "ty"], 0, "ˈtwɛnti"),
+    p("thirty", &["thir", "ty"], 0, "ˈθɜːti"),
+    p("forty", &["for", "ty"], 0, "ˈfɔːti"),
+    p("fifty", &["fif", "ty"], 0, "ˈfɪfti"),
+    p("sixty", &["six", "ty"], 0, "ˈsɪksti"),
+    p("seventy", &["sev", "en", "ty"], 0, "ˈsɛvənti"),
+    p("eighty", &["eigh", "ty"], 0, "ˈeɪti"),
+    p("ninety", &["nine", "ty"], 0, "ˈnaɪnti"),
+    p("hundred", &["hun", "dred"], 0, "ˈhʌndɹəd"),
+    p("thousand", &["thou", "sand"], 0, "ˈθaʊzənd"),
+    p("million", &["mil", "lion"], 0, "ˈmɪljən"),
+    p("billion", &["bil", "lion"], 0, "ˈbɪljən"),
+    p("trillion", &["tril", "lion"], 0, "ˈtɹɪljən"),
+    p("quadrillion", &["quad", "ril", "lion"], 1, "kwɒˈdɹɪljən"),
+    p("nought", &["nought"], 0, "nɔːt"),
+    p("nil", &["nil"], 0, "nɪl"),
+    p("oh", &["oh"], 0, "əʊ"),
+    p("and", &["and"], 0, "ənd"),
+];
+
+/// Returns the [Pronunciation](./struct.Pronunciation.html) of a lower case number word.
+///
+/// # Example
+/// ```
+/// use shortscale::pronunciation;
+///
+/// let hundred = pronunciation("hundred").unwrap();
+/// assert_eq!(hundred.syllables, ["hun", "dred"]);
+/// assert_eq!(hundred.ipa, "ˈhʌndɹəd");
+/// assert_eq!(pronunciation("zillion"), None);
+/// ```
+pub fn pronunciation(word: &str) -> Option<&'static Pronunciation> {
+    PRONUNCIATIONS.iter().find(|p| p.word == word)
+}
+
+/// Returns words with the text from a callback for each [Token](./enum.Token.html),
+/// with spaces between tokens except around a Hyphen.
+///
+/// # Example
+/// ```
+/// use shortscale::{pronunciation, shortscale_annotate, Options};
+///
+/// let ipa = shortscale_annotate(420, &Options::new(), |s, token| {
+///     match pronunciation(token.as_str()) {
+///         Some(p) => s.push_str(p.ipa),
+///         None => s.push_str(token.as_str()),
+///     }
+/// });
+/// assert_eq!(ipa, "fɔː ˈhʌndɹəd ənd ˈtwɛnti");
+/// ```
+pub fn shortscale_annotate<F>(num: u64, options: &Options, mut annotate: F) -> String
+where
+    F: FnMut(&mut String, Token),
+{
+    let mut s = String::new();
+    let mut space = false;
+    for token in shortscale_tokens_with(num, options) {
+        match token {
+            Token::Hyphen => space = false,
+            _ if space => s.push(' '),
+            _ => space = true,
+        }
+        annotate(&mut s, token);
+    }
+    s
+}
+
+/// Returns words with syllables separated by "-" and the stressed syllable in upper case,
+/// for words with more than one syllable.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_stressed;
+///
+/// assert_eq!(shortscale_stressed(420), "four HUN-dred and TWEN-ty");
+/// assert_eq!(shortscale_stressed(17), "sev-en-TEEN");
+/// ```
+pub fn shortscale_stressed(num: u64) -> String {
+    shortscale_annotate(num, &Options::new(), |s, token| {
+        match pronunciation(token.as_str()) {
+            Some(p) if p.syllables.len() > 1 => push_stressed(s, p),
+            _ => s.push_str(token.as_str()),
+        }
+    })
+}
+
+fn push_stressed(s: &mut String, p: &Pronunciation) {
+    for (i, syllable) in p.syllables.iter().enumerate() {
+        if i > 0 {
+            s.push('-');
+        }
+        match i == p.stress {
+            true => s.push_str(&syllable.to_uppercase()),
+            false => s.push_str(syllable),
+        }
+    }
+}
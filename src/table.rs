@@ -0,0 +1,118 @@
+//! Table driven writer for the default Options, with prebuilt words for each group of 3 digits.
+
+use crate::const_words::{const_group, ConstWords};
+use crate::{map, MAX};
+use std::fmt;
+
+// words for 1 to 999 without a scale word, e.g. "four hundred and twenty", one after the other
+const LEN: usize = text_len();
+static TEXT: [u8; LEN] = text();
+static WORDS: &str = match std::str::from_utf8(&TEXT) {
+    Ok(s) => s,
+    Err(_) => panic!("words are always UTF-8"),
+};
+
+// words for group n are WORDS[OFFSETS[n]..OFFSETS[n + 1]]
+const OFFSETS: [u16; 1_001] = offsets();
+static GROUP_OFFSETS: [u16; 1_001] = OFFSETS;
+
+const fn group_words(group: u64) -> ConstWords {
+    match group {
+        0 => ConstWords::new(),
+        _ => const_group(ConstWords::new(), group, 1),
+    }
+}
+
+const fn text_len() -> usize {
+    let mut len = 0;
+    let mut group = 0;
+    while group < 1_000 {
+        len += group_words(group).as_bytes().len();
+        group += 1;
+    }
+    len
+}
+
+const fn text() -> [u8; LEN] {
+    let mut text = [0; LEN];
+    let mut len = 0;
+    let mut group = 0;
+    while group < 1_000 {
+        let words = group_words(group);
+        let bytes = words.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            text[len] = bytes[i];
+            len += 1;
+            i += 1;
+        }
+        group += 1;
+    }
+    text
+}
+
+const fn offsets() -> [u16; 1_001] {
+    let mut offsets = [0; 1_001];
+    let mut group = 0;
+    while group < 1_000 {
+        let len = group_words(group as u64).as_bytes().len();
+        offsets[group + 1] = offsets[group] + len as u16;
+        group += 1;
+    }
+    offsets
+}
+
+#[inline]
+fn group(num: u64) -> &'static str {
+    let num = num as usize;
+    &WORDS[GROUP_OFFSETS[num] as usize..GROUP_OFFSETS[num + 1] as usize]
+}
+
+/// Length of the words for a group of 3 digits from 1 to 999, without a scale word.
+pub(crate) const fn group_len(num: u64) -> usize {
+    let num = num as usize;
+    (OFFSETS[num + 1] - OFFSETS[num]) as usize
+}
+
+/// Same as write_locale with EnGb and the default Options, writing one group at a time.
+pub(crate) fn write_table<W: fmt::Write>(w: &mut W, num: u64) -> fmt::Result {
+    let mut first = true;
+    for_each_part(num, |part| {
+        if !first {
+            w.write_char(' ')?;
+        }
+        first = false;
+        w.write_str(part)
+    })
+}
+
+/// Calls f with each part of the words for the default Options, to be joined with spaces:
+/// the words for a group of 3 digits, a scale word, or "and" before the last tens and units.
+#[inline(always)]
+pub(crate) fn for_each_part<E, F>(num: u64, mut f: F) -> Result<(), E>
+where
+    F: FnMut(&'static str) -> Result<(), E>,
+{
+    if num == 0 || num > MAX {
+        return f(map(num));
+    }
+    let mut first = true;
+    let mut divisor = 1_000_000_000_000_000;
+    while divisor > 1 {
+        let value = num / divisor % 1_000;
+        if value > 0 {
+            f(group(value))?;
+            f(map(divisor))?;
+            first = false;
+        }
+        divisor /= 1_000;
+    }
+    match (num % 1_000, first) {
+        (0, _) => Ok(()),
+        (value @ 1..=99, false) => {
+            f("and")?;
+            f(group(value))
+        }
+        (value, _) => f(group(value)),
+    }
+}
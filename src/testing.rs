@@ -0,0 +1,223 @@
+//! Differential checks between the implementations, requires the "testing" feature.
+//!
+//! Each implementation must return the same words as [shortscale](../fn.shortscale.html),
+//! or the same words without "and" for American style,
+//! and the words must parse back into the same number.
+//! With `RUSTFLAGS="--cfg extra"` the [extra](../extra/index.html) implementations are checked as well.
+//!
+//! # Example
+//! ```
+//! use shortscale::testing::{check_all, random_samples, samples};
+//!
+//! assert!(check_all(samples()).is_ok());
+//! assert!(check_all(random_samples(42, 10_000)).is_ok());
+//! ```
+
+use crate::locale::{EnGb, EnIn, EnUs};
+use crate::rng::XorShift;
+use crate::{shortscale, shortscale_parse, ToWords, MAX};
+use std::fmt;
+
+/// One implementation of shortscale, with the largest number it is expected to agree on.
+#[derive(Debug, Clone, Copy)]
+pub struct Implementation {
+    pub name: &'static str,
+    pub words: fn(u64) -> String,
+    pub max: u64,
+    /// False for American style, which is compared with the words without "and".
+    pub and: bool,
+}
+
+/// Implementation which returned different words, or words which did not parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub name: &'static str,
+    pub num: u64,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} for {} returned {:?}, expected {:?}",
+            self.name, self.num, self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// Returns the implementations which are compared with shortscale.
+pub fn implementations() -> Vec<Implementation> {
+    let all = |name, words| Implementation {
+        name,
+        words,
+        max: u64::MAX,
+        and: true,
+    };
+    #[cfg_attr(not(extra), allow(unused_mut))]
+    let mut list = vec![
+        all("shortscale_string_writer", |num| {
+            let mut s = String::new();
+            crate::shortscale_string_writer(&mut s, num);
+            s
+        }),
+        all("NumWords", |num| crate::NumWords::new(num).to_string()),
+        all("shortscale_locale EnGb", |num| {
+            crate::shortscale_locale(num, &EnGb)
+        }),
+        all("shortscale_ref", |num| {
+            crate::shortscale_ref(num).to_string()
+        }),
+        all("shortscale_const", |num| {
+            crate::shortscale_const(num).as_str().to_string()
+        }),
+        all("shortscale_words", |num| {
+            crate::shortscale_words(num).join(" ")
+        }),
+        all("shortscale_tokens", |num| {
+            let tokens: Vec<_> = crate::shortscale_tokens(num).map(|t| t.as_str()).collect();
+            tokens.join(" ")
+        }),
+        Implementation {
+            name: "shortscale_locale EnUs",
+            words: |num| crate::shortscale_locale(num, &EnUs),
+            max: u64::MAX,
+            and: false,
+        },
+        // lakh from 100_000
+        Implementation {
+            name: "shortscale_locale EnIn",
+            words: |num| crate::shortscale_locale(num, &EnIn),
+            max: 99_999,
+            and: true,
+        },
+        // larger numbers have words like "quintillion"
+        Implementation {
+            name: "shortscale_bigint",
+            words: |num| crate::shortscale_bigint(&num.to_string()).unwrap(),
+            max: MAX,
+            and: true,
+        },
+        Implementation {
+            name: "to_words",
+            words: |num| num.to_words(),
+            max: MAX,
+            and: true,
+        },
+    ];
+    #[cfg(extra)]
+    list.extend_from_slice(&[
+        all(
+            "extra::shortscale_display",
+            crate::extra::shortscale_display,
+        ),
+        all("extra::NumWords", |num| {
+            crate::extra::NumWords::new(num).to_string()
+        }),
+        all(
+            "extra::shortscale_str_push",
+            crate::extra::shortscale_str_push,
+        ),
+        all(
+            "extra::shortscale_vec_push",
+            crate::extra::shortscale_vec_push,
+        ),
+        all(
+            "extra::shortscale_vec_concat",
+            crate::extra::shortscale_vec_concat,
+        ),
+        all(
+            "extra::shortscale_string_join",
+            crate::extra::shortscale_string_join,
+        ),
+    ]);
+    list
+}
+
+/// Compares every implementation with shortscale for one number,
+/// and checks the length and the parsed words.
+pub fn check(num: u64) -> Result<(), Mismatch> {
+    check_with(num, &implementations())
+}
+
+/// Same as check for each number, returns how many numbers were checked.
+pub fn check_all<I: IntoIterator<Item = u64>>(nums: I) -> Result<usize, Mismatch> {
+    let implementations = implementations();
+    let mut count = 0;
+    for num in nums {
+        check_with(num, &implementations)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn check_with(num: u64, implementations: &[Implementation]) -> Result<(), Mismatch> {
+    let expected = shortscale(num);
+    let mismatch = |name, actual| Mismatch {
+        name,
+        num,
+        expected: expected.clone(),
+        actual,
+    };
+    let without_and: String = expected
+        .split(' ')
+        .filter(|word| *word != "and")
+        .collect::<Vec<_>>()
+        .join(" ");
+    for implementation in implementations.iter().filter(|i| num <= i.max) {
+        let actual = (implementation.words)(num);
+        let matches = match implementation.and {
+            true => actual == expected,
+            false => actual == without_and,
+        };
+        if !matches {
+            return Err(mismatch(implementation.name, actual));
+        }
+    }
+    if crate::shortscale_len(num) != expected.len() {
+        let len = crate::shortscale_len(num).to_string();
+        return Err(mismatch("shortscale_len", len));
+    }
+    if num <= MAX && shortscale_parse(&expected) != Ok(num) {
+        let parsed = format!("{:?}", shortscale_parse(&expected));
+        return Err(mismatch("shortscale_parse", parsed));
+    }
+    Ok(())
+}
+
+/// Returns structured samples: all numbers below 1_000_000,
+/// numbers around each power of 10, and groups of digits with each pattern of 0, 1, and 7.
+pub fn samples() -> impl Iterator<Item = u64> {
+    let boundaries = (0..=19).flat_map(|exp| {
+        let power = 10_u64.pow(exp);
+        vec![power - 1, power, power + 1]
+    });
+    // each group of 3 digits made of 0, 1, and 7, e.g. 107 or 771
+    let patterns: Vec<u64> = (0..27)
+        .map(|i| [0, 1, 7][i / 9] * 100 + [0, 1, 7][i / 3 % 3] * 10 + [0, 1, 7][i % 3])
+        .collect();
+    let single = patterns
+        .clone()
+        .into_iter()
+        .flat_map(|pattern| (0..6).map(move |scale| pattern * 1_000_u64.pow(scale)));
+    let repeated = patterns
+        .into_iter()
+        .map(|pattern| (0..6).map(|scale| pattern * 1_000_u64.pow(scale)).sum());
+    (0..1_000_000)
+        .chain(boundaries)
+        .chain(single)
+        .chain(repeated)
+        .chain(vec![MAX, MAX + 1, u64::MAX])
+}
+
+/// Returns random numbers of all magnitudes, the same numbers for the same seed.
+pub fn random_samples(seed: u64, count: usize) -> impl Iterator<Item = u64> {
+    let mut rng = XorShift::new(seed);
+    (0..count).map(move |_| {
+        let random = rng.next_u64();
+        random >> (random % 64)
+    })
+}
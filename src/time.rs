@@ -0,0 +1,127 @@
+//! Durations and clock times, e.g. "two hours, fifteen minutes and nine seconds".
+
+use crate::list::push_list;
+use crate::{count_of, shortscale_string_writer, Error};
+
+/// Returns words for a duration given in seconds, e.g. "two hours, fifteen minutes and nine seconds".
+///
+/// The duration is split into days, hours, minutes, and seconds, leaving out parts which are zero.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_duration;
+///
+/// assert_eq!(
+///     shortscale_duration(8_109),
+///     "two hours, fifteen minutes and nine seconds"
+/// );
+/// assert_eq!(shortscale_duration(90_000), "one day and one hour");
+/// assert_eq!(shortscale_duration(0), "zero seconds");
+/// ```
+pub fn shortscale_duration(secs: u64) -> String {
+    let parts = [
+        (secs / 86_400, "day"),
+        (secs / 3_600 % 24, "hour"),
+        (secs / 60 % 60, "minute"),
+        (secs % 60, "second"),
+    ];
+    let mut items: Vec<String> = parts
+        .iter()
+        .filter(|(num, _)| *num > 0)
+        .map(|(num, unit)| count_of(*num, unit, None))
+        .collect();
+    if items.is_empty() {
+        items.push(count_of(0, "second", None));
+    }
+    let mut s = String::new();
+    push_list(&mut s, &items, "and", false);
+    s
+}
+
+/// Style for [shortscale_clock_with](./fn.shortscale_clock_with.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockStyle {
+    /// Hours and minutes as on a digital clock, e.g. "three fifteen" or "three oh five".
+    #[default]
+    Digital,
+    /// Minutes past or to the hour, e.g. "quarter past three" or "ten to four",
+    /// with "noon" and "midnight".
+    Phrase,
+}
+
+/// Returns words for a time on a 12 hour clock given hours from 0 to 23 and minutes from 0 to 59.
+///
+/// Returns [Error::OutOfRange](./enum.Error.html#variant.OutOfRange) for other hours or minutes.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_clock;
+///
+/// assert_eq!(shortscale_clock(15, 15).unwrap(), "three fifteen");
+/// assert_eq!(shortscale_clock(9, 5).unwrap(), "nine oh five");
+/// assert_eq!(shortscale_clock(0, 0).unwrap(), "twelve o'clock");
+/// ```
+pub fn shortscale_clock(hour: u64, minute: u64) -> Result<String, Error> {
+    shortscale_clock_with(hour, minute, ClockStyle::Digital)
+}
+
+/// Same as shortscale_clock but with a [ClockStyle](./enum.ClockStyle.html).
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_clock_with, ClockStyle};
+///
+/// let phrase = |hour, minute| shortscale_clock_with(hour, minute, ClockStyle::Phrase).unwrap();
+/// assert_eq!(phrase(15, 15), "quarter past three");
+/// assert_eq!(phrase(15, 50), "ten to four");
+/// assert_eq!(phrase(15, 52), "eight minutes to four");
+/// assert_eq!(phrase(12, 0), "noon");
+/// ```
+pub fn shortscale_clock_with(hour: u64, minute: u64, style: ClockStyle) -> Result<String, Error> {
+    if hour > 23 {
+        return Err(Error::OutOfRange { min: 0, max: 23 });
+    }
+    if minute > 59 {
+        return Err(Error::OutOfRange { min: 0, max: 59 });
+    }
+    let mut s = String::new();
+    match (style, hour, minute) {
+        (ClockStyle::Phrase, 0, 0) => s.push_str("midnight"),
+        (ClockStyle::Phrase, 12, 0) => s.push_str("noon"),
+        (_, _, 0) => {
+            push_hour(&mut s, hour);
+            s.push_str(" o'clock");
+        }
+        (ClockStyle::Digital, _, _) => {
+            push_hour(&mut s, hour);
+            s.push(' ');
+            if minute < 10 {
+                s.push_str("oh ");
+            }
+            shortscale_string_writer(&mut s, minute);
+        }
+        (ClockStyle::Phrase, _, _) => {
+            let (minutes, word, hour) = match minute {
+                1..=30 => (minute, " past ", hour),
+                _ => (60 - minute, " to ", hour + 1),
+            };
+            match minutes {
+                15 => s.push_str("quarter"),
+                30 => s.push_str("half"),
+                _ if minutes % 5 == 0 => shortscale_string_writer(&mut s, minutes),
+                _ => s.push_str(&count_of(minutes, "minute", None)),
+            }
+            s.push_str(word);
+            push_hour(&mut s, hour);
+        }
+    }
+    Ok(s)
+}
+
+// 12 hour clock, 0 and 12 are "twelve"
+fn push_hour(s: &mut String, hour: u64) {
+    match hour % 12 {
+        0 => s.push_str("twelve"),
+        hour => shortscale_string_writer(s, hour),
+    }
+}
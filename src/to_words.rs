@@ -0,0 +1,95 @@
+//! Extension trait for words on all integer types.
+
+use crate::big::write_groups;
+use crate::locale::WordWriter;
+use crate::ordinal::{plain, to_ordinal};
+use crate::Options;
+
+/// Words for integers, e.g. `42_u32.to_words()` or `(-5_i64).to_words()`.
+///
+/// Implemented for all primitive integer types. Negative numbers start with "minus",
+/// and numbers larger than 999_999_999_999_999_999 continue with quintillion, sextillion, ...
+/// like [shortscale_bigint](./fn.shortscale_bigint.html).
+///
+/// # Example
+/// ```
+/// use shortscale::{Options, Style, ToWords};
+///
+/// assert_eq!(42_u32.to_words(), "forty two");
+/// assert_eq!((-5_i64).to_words(), "minus five");
+/// assert_eq!(22_u8.to_ordinal_words(), "twenty second");
+/// assert_eq!(
+///     u128::MAX.to_words_with(&Options::new().style(Style::US)).split(' ').next(),
+///     Some("three")
+/// );
+/// ```
+pub trait ToWords {
+    /// Returns words with [Options](./struct.Options.html).
+    fn to_words_with(&self, options: &Options) -> String;
+
+    /// Returns ordinal words with [Options](./struct.Options.html).
+    fn to_ordinal_words_with(&self, options: &Options) -> String;
+
+    /// Returns words, e.g. "forty two".
+    fn to_words(&self) -> String {
+        self.to_words_with(&Options::new())
+    }
+
+    /// Returns ordinal words, e.g. "forty second".
+    fn to_ordinal_words(&self) -> String {
+        self.to_ordinal_words_with(&Options::new())
+    }
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl ToWords for $t {
+            fn to_words_with(&self, options: &Options) -> String {
+                words(false, *self as u128, options)
+            }
+
+            fn to_ordinal_words_with(&self, options: &Options) -> String {
+                ordinal_words(false, *self as u128, options)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl ToWords for $t {
+            fn to_words_with(&self, options: &Options) -> String {
+                words(*self < 0, self.unsigned_abs() as u128, options)
+            }
+
+            fn to_ordinal_words_with(&self, options: &Options) -> String {
+                ordinal_words(*self < 0, self.unsigned_abs() as u128, options)
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+fn words(negative: bool, num: u128, options: &Options) -> String {
+    let mut s = String::new();
+    let mut out = WordWriter::new(&mut s, options);
+    // writing into a String never fails
+    if negative {
+        out.word("minus").unwrap();
+    }
+    let groups = (0..13)
+        .rev()
+        .map(|scale| ((num / 1_000_u128.pow(scale) % 1_000) as u64, scale as usize));
+    write_groups(&mut out, groups).unwrap();
+    s
+}
+
+fn ordinal_words(negative: bool, num: u128, options: &Options) -> String {
+    // write in lower case with "zero" and spaces to find the ordinal word
+    let lower = plain(options);
+    let mut s = words(negative, num, &lower);
+    to_ordinal(&mut s, 0, options);
+    s
+}
@@ -0,0 +1,102 @@
+//! Words as a stream of tokens, e.g. for speech synthesis.
+
+use crate::{map, shortscale_words, Options, Style, MAX};
+use std::fmt;
+
+/// One word or separator in the words for a number.
+///
+/// Spaces between tokens are implied, except around a Hyphen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    /// Units, teens, or tens, e.g. "seven", "seventeen", or "seventy".
+    Unit(&'static str),
+    /// Multiplier, e.g. "hundred", "thousand", or "million".
+    Scale(&'static str),
+    /// "and" before tens and units.
+    And,
+    /// "-" between tens and units, with the hyphenate option.
+    Hyphen,
+    /// "(big number)" for numbers larger than 999_999_999_999_999_999.
+    BigNumber,
+}
+
+impl Token {
+    /// Returns the text of the token.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Token::Unit(word) | Token::Scale(word) => word,
+            Token::And => "and",
+            Token::Hyphen => "-",
+            Token::BigNumber => map(u64::MAX),
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Returns an Iterator of [Tokens](./enum.Token.html) given an unsigned integer.
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_tokens, Token};
+///
+/// let tokens: Vec<Token> = shortscale_tokens(2_020).collect();
+/// assert_eq!(
+///     tokens,
+///     [
+///         Token::Unit("two"),
+///         Token::Scale("thousand"),
+///         Token::And,
+///         Token::Unit("twenty")
+///     ]
+/// );
+///
+/// // count the words
+/// assert_eq!(shortscale_tokens(420).count(), 4);
+/// ```
+pub fn shortscale_tokens(num: u64) -> impl Iterator<Item = Token> {
+    shortscale_tokens_with(num, &Options::new())
+}
+
+/// Same as shortscale_tokens but with [Options](./struct.Options.html).
+///
+/// The letter case, separator, and conjunction options are ignored, tokens are always lower case.
+///
+/// # Example
+/// ```
+/// use shortscale::{shortscale_tokens_with, Options, Token};
+///
+/// let tokens: Vec<Token> = shortscale_tokens_with(21, &Options::new().hyphenate(true)).collect();
+/// assert_eq!(tokens, [Token::Unit("twenty"), Token::Hyphen, Token::Unit("one")]);
+/// ```
+pub fn shortscale_tokens_with(num: u64, options: &Options) -> impl Iterator<Item = Token> {
+    let mut tokens = Vec::new();
+    match num {
+        0 => tokens.push(Token::Unit(options.zero.as_str())),
+        1..=MAX => {
+            for word in shortscale_words(num) {
+                let token = match word {
+                    "and" if options.style == Style::US => continue,
+                    "and" => Token::And,
+                    "hundred" | "thousand" | "million" | "billion" | "trillion" | "quadrillion" => {
+                        Token::Scale(word)
+                    }
+                    _ => Token::Unit(word),
+                };
+                // units only follow units as tens and units, e.g. "twenty one"
+                if let (Token::Unit(_), Some(Token::Unit(_))) = (token, tokens.last()) {
+                    if options.hyphenate {
+                        tokens.push(Token::Hyphen);
+                    }
+                }
+                tokens.push(token);
+            }
+        }
+        _ => tokens.push(Token::BigNumber),
+    }
+    tokens.into_iter()
+}
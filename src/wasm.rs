@@ -0,0 +1,51 @@
+//! WebAssembly bindings for JavaScript, requires the "wasm" feature.
+//!
+//! Build the cdylib with `cargo rustc`, since the crate only builds an rlib by default,
+//! then generate the bindings with `wasm-bindgen`:
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/shortscale.wasm
+//! ```
+//!
+//! In JavaScript:
+//! ```js
+//! import { shortscale, parse, ordinal } from './pkg/shortscale.js';
+//!
+//! shortscale(420n);           // "four hundred and twenty"
+//! parse("four hundred");      // 400n
+//! ordinal(22n);               // "twenty second"
+//! shortscale(10n ** 18n);     // throws Error "number out of range 0 to 999999999999999999"
+//! ```
+//!
+//! u64 values are passed as JavaScript BigInt.
+//! Out of range numbers and parse errors are thrown as JavaScript Errors.
+
+use crate::{shortscale, shortscale_ordinal, shortscale_parse, Error, MAX};
+use wasm_bindgen::prelude::*;
+
+/// Returns words given a number, or throws an Error for numbers larger than 999_999_999_999_999_999.
+#[wasm_bindgen(js_name = shortscale)]
+pub fn shortscale_js(num: u64) -> Result<String, JsError> {
+    check_range(num)?;
+    Ok(shortscale(num))
+}
+
+/// Returns a number given words, or throws an Error.
+#[wasm_bindgen(js_name = parse)]
+pub fn parse_js(words: &str) -> Result<u64, JsError> {
+    Ok(shortscale_parse(words)?)
+}
+
+/// Returns ordinal words given a number, or throws an Error for numbers larger than 999_999_999_999_999_999.
+#[wasm_bindgen(js_name = ordinal)]
+pub fn ordinal_js(num: u64) -> Result<String, JsError> {
+    check_range(num)?;
+    Ok(shortscale_ordinal(num))
+}
+
+fn check_range(num: u64) -> Result<(), Error> {
+    match num {
+        0..=MAX => Ok(()),
+        _ => Err(Error::out_of_range()),
+    }
+}
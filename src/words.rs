@@ -0,0 +1,30 @@
+//! Words as a Vec of &'static str.
+
+use crate::table::for_each_part;
+use crate::{map, MAX};
+
+/// Returns a Vec with each word given an unsigned integer.
+///
+/// The words are the same as shortscale, without allocating a String for each word,
+/// e.g. to reverse them, join them with other separators, or lay them out.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_words;
+///
+/// assert_eq!(shortscale_words(420), ["four", "hundred", "and", "twenty"]);
+/// assert_eq!(shortscale_words(1_000_000).join("_"), "one_million");
+/// ```
+pub fn shortscale_words(num: u64) -> Vec<&'static str> {
+    if num > MAX {
+        return vec![map(num)];
+    }
+    let mut words = Vec::with_capacity(8);
+    // the words for a group of 3 digits from the table are split into single words
+    let result: Result<(), ()> = for_each_part(num, |part| {
+        words.extend(part.split(' '));
+        Ok(())
+    });
+    result.unwrap();
+    words
+}
@@ -0,0 +1,79 @@
+//! Words in a reusable thread-local buffer.
+
+use crate::shortscale_string_writer;
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Deref;
+
+thread_local! {
+    static BUFFER: RefCell<String> = const { RefCell::new(String::new()) };
+}
+
+/// Returns words given an unsigned integer, without allocating a new String.
+///
+/// The words are written into a buffer which is reused by each thread.
+/// The buffer is returned to the thread when the [WordsRef](./struct.WordsRef.html) is dropped,
+/// so only nested calls, holding more than one WordsRef at a time, allocate.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_ref;
+///
+/// let mut count = 0;
+/// for num in 0..1_000 {
+///     if shortscale_ref(num).contains("seven") {
+///         count += 1;
+///     }
+/// }
+/// assert_eq!(count, 271);
+/// ```
+pub fn shortscale_ref(num: u64) -> WordsRef {
+    let mut buf = BUFFER.with(|b| b.take());
+    buf.clear();
+    shortscale_string_writer(&mut buf, num);
+    WordsRef { buf }
+}
+
+/// Words borrowed from the thread-local buffer, dereferences to &str.
+pub struct WordsRef {
+    buf: String,
+}
+
+impl Deref for WordsRef {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.buf
+    }
+}
+
+impl AsRef<str> for WordsRef {
+    fn as_ref(&self) -> &str {
+        &self.buf
+    }
+}
+
+impl fmt::Display for WordsRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.buf)
+    }
+}
+
+impl fmt::Debug for WordsRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.buf, f)
+    }
+}
+
+impl Drop for WordsRef {
+    fn drop(&mut self) {
+        let buf = std::mem::take(&mut self.buf);
+        // keep the larger buffer, ignoring a thread which is shutting down
+        let _ = BUFFER.try_with(|b| {
+            let mut b = b.borrow_mut();
+            if buf.capacity() > b.capacity() {
+                *b = buf;
+            }
+        });
+    }
+}
@@ -0,0 +1,42 @@
+//! Years are read in pairs of digits, e.g. "nineteen eighty four".
+
+use crate::{map, shortscale_string_writer};
+
+/// Returns String with words given a year, read the way years are spoken.
+///
+/// Years are split into centuries and the last two digits, e.g. 1984 is "nineteen eighty four".
+/// 2000 to 2009, and other years ending in 00 to 09 with a zero hundreds digit,
+/// are read as regular numbers.
+/// Years from 10_000 and years below 1000 are also read as regular numbers.
+///
+/// # Example
+/// ```
+/// use shortscale::shortscale_year;
+///
+/// assert_eq!(shortscale_year(1984), "nineteen eighty four");
+/// assert_eq!(shortscale_year(2007), "two thousand and seven");
+/// assert_eq!(shortscale_year(1100), "eleven hundred");
+/// assert_eq!(shortscale_year(1905), "nineteen oh five");
+/// ```
+pub fn shortscale_year(year: u64) -> String {
+    let mut s = String::new();
+    let century = year / 100;
+    let rest = year % 100;
+    if !(10..100).contains(&century) || (century % 10 == 0 && rest < 10) {
+        shortscale_string_writer(&mut s, year);
+        return s;
+    }
+    shortscale_string_writer(&mut s, century);
+    match rest {
+        0 => s.push_str(" hundred"),
+        1..=9 => {
+            s.push_str(" oh ");
+            s.push_str(map(rest));
+        }
+        _ => {
+            s.push(' ');
+            shortscale_string_writer(&mut s, rest);
+        }
+    }
+    s
+}
@@ -0,0 +1,114 @@
+use proptest::prelude::*;
+use shortscale::{shortscale, shortscale_len, shortscale_parse, shortscale_with, Options, Style};
+
+const MAX: u64 = 999_999_999_999_999_999;
+
+const WORDS: [&str; 35] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+    "twenty",
+    "thirty",
+    "forty",
+    "fifty",
+    "sixty",
+    "seventy",
+    "eighty",
+    "ninety",
+    "hundred",
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "and",
+];
+
+const SCALES: [(u64, &str); 5] = [
+    (1_000_000_000_000_000, "quadrillion"),
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+// numbers spread across all magnitudes
+fn any_num() -> impl Strategy<Value = u64> {
+    (0..=18_u32, any::<u64>()).prop_map(|(digits, n)| n % 10_u64.pow(digits))
+}
+
+proptest! {
+    #[test]
+    fn prop_spaces(num in any_num()) {
+        let words = shortscale(num);
+        prop_assert!(!words.contains("  "), "{}", words);
+        prop_assert_eq!(words.trim(), words.as_str());
+    }
+
+    #[test]
+    fn prop_and(num in any_num()) {
+        let words = shortscale(num);
+        let words: Vec<&str> = words.split(' ').collect();
+        prop_assert_ne!(words.first(), Some(&"and"), "{:?}", words);
+        prop_assert_ne!(words.last(), Some(&"and"), "{:?}", words);
+        prop_assert!(!words.windows(2).any(|w| w == ["and", "and"]), "{:?}", words);
+    }
+
+    #[test]
+    fn prop_vocabulary(num in any_num()) {
+        for word in shortscale(num).split(' ') {
+            prop_assert!(WORDS.contains(&word), "{} in {}", word, num);
+        }
+    }
+
+    #[test]
+    fn prop_magnitude(num in 1..=MAX) {
+        let words = shortscale(num);
+        match SCALES.iter().find(|(scale, _)| num >= *scale) {
+            Some((_, name)) => prop_assert!(words.contains(name), "{}", words),
+            None => prop_assert!(!SCALES.iter().any(|(_, name)| words.contains(name))),
+        }
+    }
+
+    #[test]
+    fn prop_word_count(num in 0..=MAX / 1_000) {
+        let count = shortscale(num).split(' ').count();
+        let count_thousands = shortscale(num * 1_000).split(' ').count();
+        prop_assert!(count_thousands >= count, "{} {}", num, count_thousands);
+    }
+
+    #[test]
+    fn prop_len(num in any::<u64>()) {
+        prop_assert_eq!(shortscale_len(num), shortscale(num).len());
+    }
+
+    #[test]
+    fn prop_round_trip(num in any_num()) {
+        prop_assert_eq!(shortscale_parse(&shortscale(num)), Ok(num));
+        let us = Options::new().style(Style::US).hyphenate(true);
+        prop_assert_eq!(shortscale_parse(&shortscale_with(num, &us)), Ok(num));
+    }
+
+    #[test]
+    fn prop_parse_any(words in "[a-z -]{0,64}") {
+        // must not panic
+        let _ = shortscale_parse(&words);
+    }
+}
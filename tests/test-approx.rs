@@ -0,0 +1,65 @@
+use shortscale::{
+    shortscale_approx, shortscale_approx_with, ApproxOptions, Case, Options, Prefix, Rounding,
+};
+
+#[test]
+fn test_shortscale_approx() {
+    let tests = [
+        (0, 2, "zero"),
+        (7, 2, "seven"),
+        (420, 2, "four hundred and twenty"),
+        (421, 2, "about four hundred and twenty"),
+        (425, 2, "about four hundred and thirty"),
+        (425, 0, "about four hundred"),
+        (999_600, 2, "about one million"),
+        (420_012_345_678, 2, "about four hundred and twenty billion"),
+        (
+            420_012_345_678,
+            5,
+            "about four hundred and twenty billion ten million",
+        ),
+        (u64::MAX, 2, "(big number)"),
+        (
+            999_999_999_999_999_999,
+            2,
+            "over nine hundred and ninety quadrillion",
+        ),
+    ];
+    for (num, significant, expected) in tests.iter() {
+        println!("shortscale_approx {} {}", num, significant);
+        assert_eq!(shortscale_approx(*num, *significant), *expected);
+    }
+}
+
+#[test]
+fn test_shortscale_approx_with() {
+    let tests = [
+        (Rounding::Nearest, Prefix::Auto, "about one million"),
+        (Rounding::Down, Prefix::Auto, "over nine hundred thousand"),
+        (Rounding::Up, Prefix::Auto, "under one million"),
+        (Rounding::Up, Prefix::Word("almost"), "almost one million"),
+        (Rounding::Up, Prefix::None, "one million"),
+    ];
+    for (rounding, prefix, expected) in tests.iter() {
+        println!("shortscale_approx_with {:?} {:?}", rounding, prefix);
+        let options = ApproxOptions::new()
+            .significant(1)
+            .rounding(*rounding)
+            .prefix(*prefix);
+        assert_eq!(shortscale_approx_with(960_000, &options), *expected);
+    }
+
+    let up = ApproxOptions::new().significant(1).rounding(Rounding::Up);
+    assert_eq!(shortscale_approx_with(1_049, &up), "under two thousand");
+
+    let options = ApproxOptions::new().options(Options::new().case(Case::Sentence));
+    assert_eq!(
+        shortscale_approx_with(1_234, &options),
+        "About one thousand two hundred"
+    );
+    let options = ApproxOptions::new().options(Options::new().case(Case::Title));
+    assert_eq!(
+        shortscale_approx_with(1_234, &options),
+        "About One Thousand Two Hundred"
+    );
+}
@@ -0,0 +1,108 @@
+use shortscale::{
+    shortscale, shortscale_bigint, shortscale_bigint_with, shortscale_with, Case, Error, Options,
+    Style,
+};
+
+#[test]
+fn test_shortscale_bigint() {
+    let tests = [
+        ("0", "zero".to_string()),
+        ("000", "zero".to_string()),
+        ("007", "seven".to_string()),
+        ("1000000000000000000", "one quintillion".to_string()),
+        (
+            "18446744073709551615",
+            "eighteen quintillion four hundred and forty six quadrillion \
+            seven hundred and forty four trillion seventy three billion \
+            seven hundred and nine million five hundred and fifty one thousand \
+            six hundred and fifteen"
+                .to_string(),
+        ),
+        (
+            "1000000000000000000000000000000001",
+            "one decillion and one".to_string(),
+        ),
+        (
+            "12000000000000000000000000000000000000000",
+            "twelve duodecillion".to_string(),
+        ),
+    ];
+    for (digits, expected) in tests.iter() {
+        println!("shortscale_bigint {}", digits);
+        assert_eq!(shortscale_bigint(digits).unwrap(), *expected);
+    }
+
+    let scales = [
+        (21, "sextillion"),
+        (33, "decillion"),
+        (60, "novemdecillion"),
+        (63, "vigintillion"),
+        (66, "unvigintillion"),
+        (99, "duotrigintillion"),
+        (153, "quinquagintillion"),
+        (300, "novemnonagintillion"),
+        (303, "centillion"),
+    ];
+    for (zeros, expected) in scales.iter() {
+        println!("shortscale_bigint 10^{}", zeros);
+        let digits = format!("1{}", "0".repeat(*zeros));
+        assert_eq!(
+            shortscale_bigint(&digits).unwrap(),
+            format!("one {}", expected)
+        );
+    }
+}
+
+#[test]
+fn test_shortscale_bigint_power() {
+    let digits = format!("1{}", "0".repeat(306));
+    assert_eq!(
+        shortscale_bigint(&digits).unwrap(),
+        "one times ten to the power of three hundred and six"
+    );
+
+    let digits = format!("21{}1", "0".repeat(3_006));
+    assert_eq!(
+        shortscale_bigint(&digits).unwrap(),
+        "two hundred and ten times ten to the power of three thousand and six and one"
+    );
+}
+
+#[test]
+fn test_shortscale_bigint_same_as_shortscale() {
+    let options = Options::new()
+        .style(Style::US)
+        .hyphenate(true)
+        .case(Case::Title);
+    for num in [
+        1,
+        21,
+        101,
+        1_000_001,
+        420_000_999_015,
+        999_999_999_999_999_999,
+    ]
+    .iter()
+    {
+        println!("shortscale_bigint {}", num);
+        let digits = num.to_string();
+        assert_eq!(shortscale_bigint(&digits).unwrap(), shortscale(*num));
+        assert_eq!(
+            shortscale_bigint_with(&digits, &options).unwrap(),
+            shortscale_with(*num, &options)
+        );
+    }
+}
+
+#[test]
+fn test_shortscale_bigint_errors() {
+    assert_eq!(shortscale_bigint(""), Err(Error::EmptyInput));
+    assert_eq!(
+        shortscale_bigint("12a"),
+        Err(Error::InvalidChar { ch: 'a', offset: 2 })
+    );
+    assert_eq!(
+        shortscale_bigint("-1"),
+        Err(Error::InvalidChar { ch: '-', offset: 0 })
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "bigint")]
+
+use num_bigint::BigUint;
+use shortscale::{
+    shortscale, shortscale_bigint, shortscale_for, shortscale_for_with, shortscale_ordinal,
+    shortscale_ordinal_for, shortscale_ordinal_for_with, shortscale_ordinal_with, Case, Options,
+    Style, ZeroWord,
+};
+
+#[test]
+fn test_shortscale_for() {
+    let tests = [
+        "0",
+        "7",
+        "1000000000000000000",
+        "999999999999999999999999999999999999",
+        "18446744073709551616",
+        "1000000000000000000000000000000000000000000000000000000000000000001",
+    ];
+    for digits in tests.iter() {
+        println!("shortscale_for {}", digits);
+        let num: BigUint = digits.parse().unwrap();
+        assert_eq!(shortscale_for(&num), shortscale_bigint(digits).unwrap());
+    }
+
+    let num = BigUint::from(10_u32).pow(306);
+    assert_eq!(
+        shortscale_for(&num),
+        "one times ten to the power of three hundred and six"
+    );
+}
+
+#[test]
+fn test_shortscale_for_options() {
+    let options = Options::new()
+        .style(Style::US)
+        .hyphenate(true)
+        .case(Case::Title);
+    let num = BigUint::from(10_u32).pow(21) * 21_u32 + 101_u32;
+    assert_eq!(
+        shortscale_for_with(&num, &options),
+        "Twenty-One Sextillion One Hundred One"
+    );
+    let zero = Options::new().zero(ZeroWord::Nought);
+    assert_eq!(shortscale_for_with(&BigUint::from(0_u32), &zero), "nought");
+}
+
+#[test]
+fn test_shortscale_ordinal_for() {
+    for num in [0, 1, 22, 100, 1_000_000, 420_000_999_015].iter() {
+        println!("shortscale_ordinal_for {}", num);
+        assert_eq!(
+            shortscale_ordinal_for(&BigUint::from(*num)),
+            shortscale_ordinal(*num)
+        );
+    }
+    let options = Options::new().case(Case::Title).hyphenate(true);
+    assert_eq!(
+        shortscale_ordinal_for_with(&BigUint::from(121_u32), &options),
+        shortscale_ordinal_with(121, &options)
+    );
+
+    let num = BigUint::from(10_u32).pow(18);
+    assert_eq!(shortscale_ordinal_for(&num), "one quintillionth");
+    let num = BigUint::from(10_u32).pow(303) * 3_u32;
+    assert_eq!(shortscale_ordinal_for(&num), "three centillionth");
+    assert_eq!(shortscale(1_000), shortscale_for(&BigUint::from(1_000_u32)));
+}
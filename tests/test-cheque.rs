@@ -0,0 +1,93 @@
+use shortscale::{shortscale_cheque, Case, ChequeOptions, Error, Options};
+
+#[test]
+fn test_shortscale_cheque() {
+    let tests = [
+        (0, "Zero and 00/100 Dollars ***"),
+        (5, "Zero and 05/100 Dollars ***"),
+        (100, "One and 00/100 Dollars ***"),
+        (
+            123_456,
+            "One Thousand Two Hundred Thirty-Four and 56/100 Dollars ***",
+        ),
+        (100_000_000_001, "One Billion and 01/100 Dollars ***"),
+    ];
+    for (cents, expected) in tests.iter() {
+        println!("shortscale_cheque {}", cents);
+        assert_eq!(
+            shortscale_cheque(*cents, &ChequeOptions::new()).unwrap(),
+            *expected
+        );
+    }
+    assert!(shortscale_cheque(u64::MAX, &ChequeOptions::new()).is_ok());
+}
+
+#[test]
+fn test_shortscale_cheque_width() {
+    let cheque = ChequeOptions::new().filler('-').width(40);
+    assert_eq!(
+        shortscale_cheque(2_050, &cheque).unwrap(),
+        "Twenty and 50/100 Dollars --------------"
+    );
+
+    // no room for filler
+    let cheque = ChequeOptions::new().width(26);
+    assert_eq!(
+        shortscale_cheque(2_050, &cheque).unwrap(),
+        "Twenty and 50/100 Dollars"
+    );
+
+    let cheque = ChequeOptions::new().width(32);
+    assert_eq!(
+        shortscale_cheque(123_456, &cheque),
+        Err(Error::Width {
+            width: 32,
+            needed: 55
+        })
+    );
+
+    let cheque = cheque.wrap(true);
+    let text = shortscale_cheque(123_456, &cheque).unwrap();
+    assert_eq!(
+        text,
+        "One Thousand Two Hundred\nThirty-Four and 56/100 Dollars *"
+    );
+
+    let cheque = ChequeOptions::new().width(8).wrap(true);
+    assert_eq!(
+        shortscale_cheque(123_400, &cheque),
+        Err(Error::Width {
+            width: 8,
+            needed: 11
+        })
+    );
+}
+
+#[test]
+fn test_shortscale_cheque_options() {
+    let cheque = ChequeOptions::new()
+        .currency("POUNDS")
+        .options(Options::new().case(Case::Upper));
+    assert_eq!(
+        shortscale_cheque(10_199, &cheque).unwrap(),
+        "ONE HUNDRED AND ONE AND 99/100 POUNDS ***"
+    );
+
+    let cheque = ChequeOptions::new().options(Options::new().case(Case::Upper));
+    assert_eq!(
+        shortscale_cheque(12_000, &cheque).unwrap(),
+        "ONE HUNDRED AND TWENTY AND 00/100 DOLLARS ***"
+    );
+    let cheque = ChequeOptions::new()
+        .currency("euros")
+        .options(Options::new().case(Case::Title));
+    assert_eq!(
+        shortscale_cheque(2_050, &cheque).unwrap(),
+        "Twenty and 50/100 Euros ***"
+    );
+    let cheque = ChequeOptions::new().options(Options::new().case(Case::Sentence));
+    assert_eq!(
+        shortscale_cheque(2_050, &cheque).unwrap(),
+        "Twenty and 50/100 dollars ***"
+    );
+}
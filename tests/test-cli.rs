@@ -0,0 +1,57 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn shortscale(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_shortscale"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn test_cli() {
+    let output = shortscale(&["420000999015", "22"], "");
+    assert!(output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "four hundred and twenty billion nine hundred and ninety nine thousand and fifteen\ntwenty two\n"
+    );
+
+    let output = shortscale(&["--ordinal", "--style=us"], "101\n1,000\n");
+    assert_eq!(stdout(&output), "one hundred first\none thousandth\n");
+
+    let output = shortscale(&["--parse", "forty", "two"], "");
+    assert_eq!(stdout(&output), "42\n");
+
+    let output = shortscale(&["--parse", "--json"], "twenty two\n\"zillion\"\n");
+    assert!(!output.status.success());
+    assert_eq!(
+        stdout(&output),
+        "{\"input\":\"twenty two\",\"output\":22}\n\
+        {\"input\":\"\\\"zillion\\\"\",\"error\":\"unknown word \\\"\\\\\\\"zillion\\\\\\\"\\\" at offset 0\"}\n"
+    );
+
+    let output = shortscale(&["--json", "7"], "");
+    assert_eq!(stdout(&output), "{\"input\":\"7\",\"output\":\"seven\"}\n");
+
+    let output = shortscale(&["abc"], "");
+    assert_eq!(output.status.code(), Some(1));
+    let output = shortscale(&["--bogus"], "");
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,27 @@
+use shortscale::shortscale_compact;
+
+#[test]
+fn test_shortscale_compact() {
+    let tests = [
+        (0, 1, "0"),
+        (999, 1, "999"),
+        (1_000, 1, "1 thousand"),
+        (1_050, 1, "1.1 thousand"),
+        (1_049, 1, "1 thousand"),
+        (1_234_567, 0, "1 million"),
+        (1_234_567, 1, "1.2 million"),
+        (1_234_567, 3, "1.235 million"),
+        (1_234_567, 18, "1.234567 million"),
+        (999_999, 1, "1 million"),
+        (999_949, 1, "999.9 thousand"),
+        (420_000_999_015, 2, "420 billion"),
+        (999_900_000_000_000, 1, "999.9 trillion"),
+        (999_999_999_999_999_999, 1, "999.9 quadrillion"),
+        (999_999_999_999_999_999, 0, "999 quadrillion"),
+        (u64::MAX, 1, "(big number)"),
+    ];
+    for (num, decimals, expected) in tests.iter() {
+        println!("shortscale_compact {} {}", num, decimals);
+        assert_eq!(shortscale_compact(*num, *decimals), *expected);
+    }
+}
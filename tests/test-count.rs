@@ -0,0 +1,33 @@
+use shortscale::{count_of, count_of_numeric, ToWords};
+
+#[test]
+fn test_count_of() {
+    let tests = [
+        (0, "item", None, "zero items"),
+        (1, "file", None, "one file"),
+        (22, "file", None, "twenty two files"),
+        (2, "box", None, "two boxes"),
+        (2, "bus", None, "two buses"),
+        (2, "batch", None, "two batches"),
+        (2, "dish", None, "two dishes"),
+        (2, "category", None, "two categories"),
+        (2, "day", None, "two days"),
+        (2, "y", None, "two ys"),
+        (3, "child", Some("children"), "three children"),
+        (1, "child", Some("children"), "one child"),
+        (2, "", None, "two"),
+        (1, "", None, "one"),
+    ];
+    for (num, singular, plural, expected) in tests.iter() {
+        println!("count_of {} {}", num, singular);
+        assert_eq!(count_of(*num, singular, *plural), *expected);
+    }
+    assert_eq!(count_of_numeric(22, "file", None), "22 files");
+    assert_eq!(count_of_numeric(1, "mouse", Some("mice")), "1 mouse");
+    assert_eq!(count_of_numeric(2, "", None), "2");
+    assert_eq!(
+        count_of(u64::MAX, "byte", None),
+        format!("{} bytes", u64::MAX.to_words())
+    );
+    assert!(count_of(u64::MAX, "byte", None).starts_with("eighteen quintillion "));
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "de")]
+
+use shortscale::locale::De;
+use shortscale::shortscale_locale;
+
+const TESTS: [(u64, &str); 24] = [
+    (0, "null"),
+    (1, "eins"),
+    (2, "zwei"),
+    (11, "elf"),
+    (16, "sechzehn"),
+    (21, "einundzwanzig"),
+    (30, "dreißig"),
+    (99, "neunundneunzig"),
+    (100, "einhundert"),
+    (101, "einhunderteins"),
+    (121, "einhunderteinundzwanzig"),
+    (1_000, "eintausend"),
+    (1_001, "eintausendeins"),
+    (2_354, "zweitausenddreihundertvierundfünfzig"),
+    (21_000, "einundzwanzigtausend"),
+    (1_000_000, "eine Million"),
+    (1_000_001, "eine Million eins"),
+    (2_000_000, "zwei Millionen"),
+    (101_000_000, "einhunderteine Millionen"),
+    (1_300_000, "eine Million dreihunderttausend"),
+    (1_000_000_000, "eine Milliarde"),
+    (
+        420_000_999_015,
+        "vierhundertzwanzig Milliarden neunhundertneunundneunzigtausendfünfzehn",
+    ),
+    (2_000_000_000_000_000, "zwei Billiarden"),
+    (1_999_999_999_999_999_999, "(große Zahl)"),
+];
+
+#[test]
+fn test_de() {
+    for (num, expected) in TESTS.iter() {
+        println!("De {}", num);
+        assert_eq!(shortscale_locale(*num, &De), *expected);
+    }
+}
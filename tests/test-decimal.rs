@@ -0,0 +1,61 @@
+use shortscale::{shortscale_decimal, DecimalStyle, Error};
+
+const TESTS: [(&str, &str, &str); 10] = [
+    ("0", "zero", "zero"),
+    ("3", "three", "three"),
+    ("3.", "three", "three"),
+    ("3.0", "three point zero", "three"),
+    ("3.14", "three point one four", "three and fourteen hundredths"),
+    ("3.01", "three point zero one", "three and one hundredth"),
+    (".5", "zero point five", "five tenths"),
+    ("0.25", "zero point two five", "twenty five hundredths"),
+    ("120.0075", "one hundred and twenty point zero zero seven five", "one hundred and twenty and seventy five ten thousandths"),
+    ("1.00000000000000001", "one point zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero one", "one and one hundred quadrillionth"),
+];
+
+#[test]
+fn test_shortscale_decimal() {
+    for (num, point, fraction) in TESTS.iter() {
+        println!("shortscale_decimal {}", num);
+        assert_eq!(
+            shortscale_decimal(num, DecimalStyle::Point).unwrap(),
+            *point
+        );
+        assert_eq!(
+            shortscale_decimal(num, DecimalStyle::Fraction).unwrap(),
+            *fraction
+        );
+    }
+}
+
+#[test]
+fn test_shortscale_decimal_errors() {
+    let point = DecimalStyle::Point;
+    assert_eq!(shortscale_decimal("", point), Err(Error::EmptyInput));
+    assert_eq!(
+        shortscale_decimal(".", point),
+        Err(Error::InvalidChar { ch: '.', offset: 0 })
+    );
+    assert_eq!(
+        shortscale_decimal("-3.14", point),
+        Err(Error::InvalidChar { ch: '-', offset: 0 })
+    );
+    assert_eq!(
+        shortscale_decimal("3.1.4", point),
+        Err(Error::InvalidChar { ch: '.', offset: 3 })
+    );
+    assert_eq!(
+        shortscale_decimal("1000000000000000000.5", point),
+        Err(Error::OutOfRange {
+            min: 0,
+            max: 999_999_999_999_999_999
+        })
+    );
+    assert_eq!(
+        shortscale_decimal("1.000000000000000001", DecimalStyle::Fraction),
+        Err(Error::OutOfRange {
+            min: 0,
+            max: 999_999_999_999_999_999
+        })
+    );
+}
@@ -0,0 +1,94 @@
+use shortscale::dictation::{
+    check_answer, check_answer_with, check_ordinal_answer, check_ordinal_answer_with, Dictation,
+};
+use shortscale::{Case, Options, Style, ZeroWord};
+
+#[test]
+fn test_dictation() {
+    let exercises: Vec<_> = Dictation::new(1..=100, 42).take(100).collect();
+    for exercise in exercises.iter() {
+        println!("{} {}", exercise.number, exercise.words);
+        assert!((1..=100).contains(&exercise.number));
+        assert_eq!(exercise.words, shortscale::shortscale(exercise.number));
+        assert!(exercise.check(&exercise.words));
+        assert!(exercise.check_ordinal(&exercise.ordinal_words));
+    }
+    let again: Vec<_> = Dictation::new(1..=100, 42).take(100).collect();
+    assert_eq!(exercises, again);
+    let other: Vec<_> = Dictation::new(1..=100, 43).take(100).collect();
+    assert_ne!(exercises, other);
+
+    let single = Dictation::new(7..=7, 0).next().unwrap();
+    assert_eq!(single.number, 7);
+    assert_eq!(single.ordinal_words, "seventh");
+    let big = Dictation::new(u64::MAX - 1..=u64::MAX, 0).next().unwrap();
+    assert_eq!(big.number, 999_999_999_999_999_999);
+
+    let options = Options::new().style(Style::US).hyphenate(true);
+    let us = Dictation::new(121..=121, 0)
+        .options(options)
+        .next()
+        .unwrap();
+    assert_eq!(us.words, "one hundred twenty-one");
+    assert_eq!(us.ordinal_words, "one hundred twenty-first");
+}
+
+#[test]
+fn test_check_answer() {
+    assert!(check_answer(121, "one hundred and twenty one"));
+    assert!(check_answer(121, "One Hundred Twenty-One"));
+    assert!(check_answer(121, "  one  hundred, twenty-one "));
+    assert!(check_answer(1_001, "one thousand and one"));
+    assert!(!check_answer(121, "one hundred and twelve"));
+    assert!(!check_answer(121, "one hundred twenty"));
+    assert!(!check_answer(121, ""));
+    assert!(!check_answer(u64::MAX, "(big number)"));
+
+    assert!(check_ordinal_answer(21, "twenty-first"));
+    assert!(check_ordinal_answer(100, "One Hundredth"));
+    assert!(!check_ordinal_answer(21, "twenty one"));
+}
+
+#[test]
+fn test_dictation_options() {
+    let options = [
+        Options::new().zero(ZeroWord::Nought),
+        Options::new().separator("_"),
+        Options::new().conjunction("und"),
+        Options::new().case(Case::Title).hyphenate(true),
+        Options::new().style(Style::US).separator("-"),
+        Options::new().separator(""),
+    ];
+    for options in options.iter() {
+        for exercise in Dictation::new(0..=1_000, 7).options(*options).take(50) {
+            println!("{:?} {}", options, exercise.words);
+            assert!(exercise.check(&exercise.words));
+            assert!(exercise.check_ordinal(&exercise.ordinal_words));
+        }
+        let zero = Dictation::new(0..=0, 0).options(*options).next().unwrap();
+        assert!(zero.check(&zero.words));
+    }
+
+    let nought = Options::new().zero(ZeroWord::Nought);
+    assert!(check_answer_with(0, "nought", &nought));
+    assert!(!check_answer_with(0, "zero", &nought));
+    let underscore = Options::new().separator("_");
+    assert!(check_answer_with(
+        420,
+        "four_hundred_and_twenty",
+        &underscore
+    ));
+    assert!(check_answer_with(420, "four hundred twenty", &underscore));
+    let empty = Options::new().separator("");
+    assert!(check_answer_with(420, "fourhundredandtwenty", &empty));
+    assert!(check_answer_with(420, "Four hundred twenty", &empty));
+    assert!(!check_answer_with(420, "four hundred", &empty));
+    assert!(check_ordinal_answer_with(21, "twenty first", &empty));
+    let und = Options::new().conjunction("und");
+    assert!(check_answer_with(101, "one hundred und one", &und));
+    assert!(check_ordinal_answer_with(
+        101,
+        "one_hundred_and_first",
+        &underscore
+    ));
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "es")]
+
+use shortscale::locale::{Es, Gender};
+use shortscale::shortscale_locale;
+
+const TESTS: [(u64, &str); 28] = [
+    (0, "cero"),
+    (1, "uno"),
+    (15, "quince"),
+    (16, "dieciséis"),
+    (21, "veintiuno"),
+    (22, "veintidós"),
+    (30, "treinta"),
+    (31, "treinta y uno"),
+    (99, "noventa y nueve"),
+    (100, "cien"),
+    (101, "ciento uno"),
+    (200, "doscientos"),
+    (555, "quinientos cincuenta y cinco"),
+    (1_000, "mil"),
+    (1_001, "mil uno"),
+    (2_000, "dos mil"),
+    (21_000, "veintiún mil"),
+    (31_000, "treinta y un mil"),
+    (100_000, "cien mil"),
+    (1_000_000, "un millón"),
+    (2_000_000, "dos millones"),
+    (21_000_000, "veintiún millones"),
+    (1_000_000_000, "mil millones"),
+    (1_500_000_000, "mil quinientos millones"),
+    (999_999_999_999, "novecientos noventa y nueve mil novecientos noventa y nueve millones novecientos noventa y nueve mil novecientos noventa y nueve"),
+    (1_000_000_000_000, "un billón"),
+    (2_000_000_000_000_000, "dos mil billones"),
+    (1_999_999_999_999_999_999, "(número grande)"),
+];
+
+const TESTS_FEMININE: [(u64, &str); 6] = [
+    (1, "una"),
+    (21, "veintiuna"),
+    (201, "doscientas una"),
+    (21_000, "veintiuna mil"),
+    (200_000, "doscientas mil"),
+    (200_000_000, "doscientos millones"),
+];
+
+#[test]
+fn test_es() {
+    for (num, expected) in TESTS.iter() {
+        println!("Es {}", num);
+        assert_eq!(shortscale_locale(*num, &Es::default()), *expected);
+    }
+    let feminine = Es {
+        gender: Gender::Feminine,
+    };
+    for (num, expected) in TESTS_FEMININE.iter() {
+        println!("Es feminine {}", num);
+        assert_eq!(shortscale_locale(*num, &feminine), *expected);
+    }
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "capi")]
+
+use shortscale::ffi::*;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::ptr;
+
+fn words(buf: &[c_char]) -> &str {
+    unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap()
+}
+
+#[test]
+fn test_shortscale_c() {
+    let mut buf = [0 as c_char; 64];
+    let len = unsafe { shortscale_c(420, buf.as_mut_ptr(), buf.len()) };
+    println!("{} => {}", 420, words(&buf));
+    assert_eq!(len, 23);
+    assert_eq!(words(&buf), "four hundred and twenty");
+
+    let len = unsafe { shortscale_ordinal_c(22, buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(len, 13);
+    assert_eq!(words(&buf), "twenty second");
+
+    // exact fit including the NUL terminator
+    let mut small = [1 as c_char; 5];
+    assert_eq!(
+        unsafe { shortscale_c(0, small.as_mut_ptr(), small.len()) },
+        4
+    );
+    assert_eq!(words(&small), "zero");
+    assert_eq!(
+        unsafe { shortscale_c(420, small.as_mut_ptr(), small.len()) },
+        SHORTSCALE_ERR_BUFFER
+    );
+    assert_eq!(words(&small), "");
+
+    assert_eq!(
+        unsafe { shortscale_c(u64::MAX, buf.as_mut_ptr(), buf.len()) },
+        SHORTSCALE_ERR_RANGE
+    );
+    assert_eq!(
+        unsafe { shortscale_c(1, ptr::null_mut(), 0) },
+        SHORTSCALE_ERR_NULL
+    );
+}
+
+#[test]
+fn test_shortscale_buf_len() {
+    let mut buf = [0 as c_char; SHORTSCALE_BUF_LEN];
+    let num = 777_777_777_777_777_777;
+    let len = unsafe { shortscale_c(num, buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(len as usize, shortscale::MAX_LEN);
+    let len = unsafe { shortscale_ordinal_c(num, buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(len as usize, SHORTSCALE_BUF_LEN - 1);
+}
+
+#[test]
+fn test_shortscale_parse_c() {
+    let mut num = 0;
+    let words = b"four hundred and twenty\0";
+    let result = unsafe { shortscale_parse_c(words.as_ptr() as *const c_char, &mut num) };
+    assert_eq!(result, 0);
+    assert_eq!(num, 420);
+
+    let words = b"four hundred and bananas\0";
+    let result = unsafe { shortscale_parse_c(words.as_ptr() as *const c_char, &mut num) };
+    assert_eq!(result, SHORTSCALE_ERR_PARSE);
+    assert_eq!(num, 420);
+
+    let result = unsafe { shortscale_parse_c(ptr::null(), &mut num) };
+    assert_eq!(result, SHORTSCALE_ERR_NULL);
+}
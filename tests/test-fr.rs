@@ -0,0 +1,51 @@
+#![cfg(feature = "fr")]
+
+use shortscale::locale::Fr;
+use shortscale::shortscale_locale;
+
+const TESTS: [(u64, &str); 33] = [
+    (0, "zéro"),
+    (1, "un"),
+    (16, "seize"),
+    (17, "dix-sept"),
+    (21, "vingt-et-un"),
+    (22, "vingt-deux"),
+    (61, "soixante-et-un"),
+    (70, "soixante-dix"),
+    (71, "soixante-et-onze"),
+    (72, "soixante-douze"),
+    (79, "soixante-dix-neuf"),
+    (80, "quatre-vingts"),
+    (81, "quatre-vingt-un"),
+    (90, "quatre-vingt-dix"),
+    (91, "quatre-vingt-onze"),
+    (99, "quatre-vingt-dix-neuf"),
+    (100, "cent"),
+    (101, "cent un"),
+    (180, "cent quatre-vingts"),
+    (200, "deux cents"),
+    (201, "deux cent un"),
+    (1_000, "mille"),
+    (1_001, "mille un"),
+    (2_000, "deux mille"),
+    (80_000, "quatre-vingt mille"),
+    (200_000, "deux cent mille"),
+    (1_000_000, "un million"),
+    (2_000_000, "deux millions"),
+    (80_000_000, "quatre-vingts millions"),
+    (200_000_000, "deux cents millions"),
+    (1_000_000_000, "un milliard"),
+    (
+        420_000_999_015,
+        "quatre cent vingt milliards neuf cent quatre-vingt-dix-neuf mille quinze",
+    ),
+    (1_999_999_999_999_999_999, "(grand nombre)"),
+];
+
+#[test]
+fn test_fr() {
+    for (num, expected) in TESTS.iter() {
+        println!("Fr {}", num);
+        assert_eq!(shortscale_locale(*num, &Fr), *expected);
+    }
+}
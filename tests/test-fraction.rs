@@ -0,0 +1,39 @@
+use shortscale::shortscale_fraction;
+
+#[test]
+fn test_shortscale_fraction() {
+    let tests = [
+        (1, 2, "one half"),
+        (3, 2, "three halves"),
+        (1, 3, "one third"),
+        (2, 3, "two thirds"),
+        (1, 4, "one quarter"),
+        (3, 4, "three quarters"),
+        (0, 5, "zero fifths"),
+        (5, 8, "five eighths"),
+        (7, 16, "seven sixteenths"),
+        (1, 21, "one twenty first"),
+        (11, 12, "eleven twelfths"),
+        (9, 10, "nine tenths"),
+        (1, 100, "one hundredth"),
+        (3, 1_000, "three thousandths"),
+        (7, 10_000, "seven ten thousandths"),
+        (1, 1_000_000, "one millionth"),
+        (1, 300, "one over three hundred"),
+        (2, 101, "two over one hundred and one"),
+        (1, 0, "one over zero"),
+        (42, 1, "forty two"),
+        (1, 10_000_000_000_000_000_000, "one ten quintillionth"),
+        (3, 10_000_000_000_000_000_000, "three ten quintillionths"),
+        (
+            1,
+            12_000_000_000_000_000_001,
+            "one over twelve quintillion and one",
+        ),
+        (1_000_000_000_000_000_000, 3, "one quintillion thirds"),
+    ];
+    for (numerator, denominator, expected) in tests.iter() {
+        println!("shortscale_fraction {}/{}", numerator, denominator);
+        assert_eq!(shortscale_fraction(*numerator, *denominator), *expected);
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "ja")]
+
+use shortscale::shortscale_ja;
+
+const TESTS: [(u64, &str); 16] = [
+    (0, "零"),
+    (1, "一"),
+    (10, "十"),
+    (11, "十一"),
+    (20, "二十"),
+    (100, "百"),
+    (101, "百一"),
+    (1_000, "千"),
+    (2_024, "二千二十四"),
+    (10_000, "一万"),
+    (10_001, "一万一"),
+    (100_000_000, "一億"),
+    (123_456_789, "一億二千三百四十五万六千七百八十九"),
+    (1_000_000_000_000, "一兆"),
+    (10_000_000_000_000_000, "一京"),
+    (
+        u64::MAX,
+        "千八百四十四京六千七百四十四兆七百三十七億九百五十五万千六百十五",
+    ),
+];
+
+#[test]
+fn test_ja() {
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_ja {}", num);
+        assert_eq!(shortscale_ja(*num), *expected);
+    }
+}
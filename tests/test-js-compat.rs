@@ -0,0 +1,45 @@
+#![cfg(feature = "js_compat")]
+
+use shortscale::js_compat::{self, BIG_NUMBER, MAX_SAFE_INTEGER};
+
+const FIXTURES: &str = include_str!("fixtures/js-shortscale.txt");
+
+fn fixtures() -> impl Iterator<Item = (u64, &'static str)> {
+    FIXTURES
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| {
+            let (num, words) = line.split_once('\t').unwrap();
+            (num.parse().unwrap(), words)
+        })
+}
+
+#[test]
+fn test_js_compat_fixtures() {
+    assert!(fixtures().count() > 30);
+    for (num, expected) in fixtures() {
+        println!("js_compat {}", num);
+        assert_eq!(js_compat::shortscale_u64(num), expected);
+        if num <= MAX_SAFE_INTEGER {
+            assert_eq!(js_compat::shortscale(num as f64), expected);
+        }
+    }
+}
+
+#[test]
+fn test_js_compat_numbers() {
+    // 2^53 + 1 is not a JavaScript number, the nearest is 2^53
+    assert_eq!(
+        js_compat::shortscale(9_007_199_254_740_993_u64 as f64),
+        shortscale::shortscale(9_007_199_254_740_992)
+    );
+    assert_eq!(
+        js_compat::shortscale(999_999_999_999_999_872.0),
+        shortscale::shortscale(999_999_999_999_999_872)
+    );
+    for num in [1e18, 1e300, -1.0, 1.5, -0.5, f64::NAN, f64::INFINITY].iter() {
+        println!("js_compat {}", num);
+        assert_eq!(js_compat::shortscale(*num), BIG_NUMBER);
+    }
+    assert_eq!(js_compat::shortscale(-0.0), "zero");
+}
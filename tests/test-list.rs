@@ -0,0 +1,31 @@
+use shortscale::{join_words_list, ListOptions, Options, Style};
+
+#[test]
+fn test_join_words_list() {
+    let plain = ListOptions::new();
+    let oxford = ListOptions::new().oxford_comma(true);
+    let tests: [(&[u64], &str, &str); 5] = [
+        (&[], "", ""),
+        (&[1], "one", "one"),
+        (&[1, 2], "one and two", "one and two"),
+        (&[1, 2, 3], "one, two and three", "one, two, and three"),
+        (
+            &[1, 2, 3, 101],
+            "one, two, three and one hundred and one",
+            "one, two, three, and one hundred and one",
+        ),
+    ];
+    for (items, expected, expected_oxford) in tests.iter() {
+        println!("join_words_list {:?}", items);
+        assert_eq!(join_words_list(items, &plain), *expected);
+        assert_eq!(join_words_list(items, &oxford), *expected_oxford);
+    }
+
+    let us_or = ListOptions::new()
+        .conjunction("or")
+        .options(Options::new().style(Style::US).hyphenate(true));
+    assert_eq!(
+        join_words_list(&[21, 101, 1_000], &us_or),
+        "twenty-one, one hundred one or one thousand"
+    );
+}
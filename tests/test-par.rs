@@ -0,0 +1,36 @@
+#![cfg(feature = "rayon")]
+
+use shortscale::{shortscale, shortscale_iter, shortscale_par_bulk, shortscale_par_for_each};
+use std::sync::mpsc::channel;
+use std::sync::Mutex;
+
+#[test]
+fn test_shortscale_par_bulk() {
+    let nums: Vec<u64> = (0..100_000).map(|i| i * 7_919_191_919).collect();
+    let words = shortscale_par_bulk(&nums);
+    assert_eq!(words, shortscale_iter(nums).collect::<Vec<String>>());
+    assert!(shortscale_par_bulk(&[]).is_empty());
+}
+
+#[test]
+fn test_shortscale_par_for_each() {
+    let nums: Vec<u64> = (0..10_000).collect();
+    let (sender, receiver) = channel();
+    shortscale_par_for_each(&nums, |i, words| {
+        sender.send((i, words.to_string())).unwrap();
+    });
+    drop(sender);
+    let mut results: Vec<(usize, String)> = receiver.iter().collect();
+    results.sort();
+    assert_eq!(results.len(), nums.len());
+    for (i, words) in results.iter() {
+        assert_eq!(*words, shortscale(nums[*i]));
+    }
+
+    let total = Mutex::new(0);
+    shortscale_par_for_each(&nums, |_, words| {
+        *total.lock().unwrap() += words.len();
+    });
+    let expected: usize = nums.iter().map(|num| shortscale(*num).len()).sum();
+    assert_eq!(*total.lock().unwrap(), expected);
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "perf-test")]
+
+#[cfg(not(debug_assertions))]
+use shortscale::perf::max_ns_per_conversion;
+use shortscale::perf::measure;
+
+#[test]
+fn test_measure() {
+    let measurements = measure(10);
+    assert_eq!(measurements.len(), 5);
+    for m in measurements.iter() {
+        println!("{} {:.0} ns", m.name, m.ns_per_conversion);
+        assert!(m.ns_per_conversion > 0.0);
+    }
+}
+
+// release builds only, run with
+// cargo test --release --features perf-test --test test-perf
+// parse is the slowest at about 1.5µs
+#[cfg(not(debug_assertions))]
+#[test]
+fn test_max_ns_per_conversion() {
+    let max = max_ns_per_conversion();
+    println!("max_ns_per_conversion {:.0}", max);
+    assert!(max < 10_000.0);
+}
@@ -0,0 +1,55 @@
+use shortscale::roman::{from_roman, to_roman};
+use shortscale::Error;
+
+const TESTS: [(u64, &str); 12] = [
+    (1, "I"),
+    (3, "III"),
+    (4, "IV"),
+    (9, "IX"),
+    (14, "XIV"),
+    (40, "XL"),
+    (90, "XC"),
+    (400, "CD"),
+    (1984, "MCMLXXXIV"),
+    (2024, "MMXXIV"),
+    (3888, "MMMDCCCLXXXVIII"),
+    (3999, "MMMCMXCIX"),
+];
+
+#[test]
+fn test_roman() {
+    for (num, numeral) in TESTS.iter() {
+        println!("roman {}", num);
+        assert_eq!(to_roman(*num).unwrap(), *numeral);
+        assert_eq!(from_roman(numeral), Ok(*num));
+        assert_eq!(from_roman(&numeral.to_lowercase()), Ok(*num));
+    }
+    for num in 1..=3999 {
+        assert_eq!(from_roman(&to_roman(num).unwrap()), Ok(num));
+    }
+}
+
+#[test]
+fn test_roman_errors() {
+    let out_of_range = Err(Error::OutOfRange { min: 1, max: 3999 });
+    assert_eq!(to_roman(0), out_of_range);
+    assert_eq!(to_roman(4000), out_of_range);
+
+    assert_eq!(from_roman(""), Err(Error::EmptyInput));
+    assert_eq!(
+        from_roman("XIV!"),
+        Err(Error::InvalidChar { ch: '!', offset: 3 })
+    );
+    assert_eq!(
+        from_roman("IIII"),
+        Err(Error::InvalidChar { ch: 'I', offset: 1 })
+    );
+    assert_eq!(
+        from_roman("IC"),
+        Err(Error::InvalidChar { ch: 'I', offset: 0 })
+    );
+    assert_eq!(
+        from_roman("MMMM"),
+        Err(Error::OutOfRange { min: 1, max: 3999 })
+    );
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use shortscale::serde_words::Words;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "shortscale::serde_words")]
+    retries: u64,
+    timeout: Words,
+}
+
+#[test]
+fn test_serde_words() {
+    let config = Config {
+        retries: 22,
+        timeout: Words(1_500),
+    };
+    let json = r#"{"retries":"twenty two","timeout":"one thousand five hundred"}"#;
+    assert_eq!(serde_json::to_string(&config).unwrap(), json);
+    assert_eq!(serde_json::from_str::<Config>(json).unwrap(), config);
+}
+
+#[test]
+fn test_serde_words_errors() {
+    let err = serde_json::from_str::<Words>(r#""twenty zillion""#).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("unknown word \"zillion\" at offset 7"));
+
+    assert!(serde_json::from_str::<Words>("22").is_err());
+    assert!(serde_json::to_string(&Words(u64::MAX)).is_err());
+}
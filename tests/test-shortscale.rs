@@ -1,3 +1,4 @@
+#[allow(clippy::single_component_path_imports)]
 use shortscale;
 
 const TESTS: [(u64, &str); 48] = [
@@ -73,6 +74,58 @@ fn test_shortscale() {
     }
 }
 
+#[test]
+fn test_shortscale_iter() {
+    let nums = TESTS.iter().map(|(num, _)| *num);
+    for (words, (num, expected)) in shortscale::shortscale_iter(nums).zip(TESTS.iter()) {
+        println!("shortscale_iter {}", num);
+        assert_eq!(words, *expected);
+    }
+
+    let mut buf = String::from("Hello ");
+    shortscale::shortscale_extend(&mut buf, TESTS.iter().map(|(num, _)| *num), ", ");
+    let expected: Vec<&str> = TESTS.iter().map(|(_, words)| *words).collect();
+    assert_eq!(buf, ["Hello ", &expected.join(", ")].concat());
+
+    let mut buf = String::new();
+    shortscale::shortscale_extend(&mut buf, Vec::new(), ", ");
+    assert_eq!(buf, "");
+}
+
+#[test]
+fn test_scales() {
+    let scales = shortscale::scales();
+    assert_eq!(scales.len(), 5);
+    for (i, (value, name)) in scales.iter().enumerate() {
+        let power = 3 * (i as u32 + 1);
+        println!("scale {} {}", value, name);
+        assert_eq!(*value, 10_u64.pow(power));
+        assert_eq!(shortscale::shortscale(*value), format!("one {}", name));
+        assert_eq!(shortscale::scale_name(power), Some(*name));
+    }
+    for power in [0, 1, 2, 4, 18, 20, u32::MAX].iter() {
+        assert_eq!(shortscale::scale_name(*power), None);
+    }
+}
+
+#[test]
+fn test_shortscale_words() {
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_words {}", num);
+        assert_eq!(shortscale::shortscale_words(*num).join(" "), *expected);
+    }
+    for num in (0..2_000_000).step_by(997) {
+        assert_eq!(
+            shortscale::shortscale_words(num).join(" "),
+            shortscale::shortscale(num)
+        );
+    }
+    assert_eq!(
+        shortscale::shortscale_words(420),
+        ["four", "hundred", "and", "twenty"]
+    );
+}
+
 #[cfg(extra)]
 #[test]
 fn test_shortscale_extra() {
@@ -110,3 +163,906 @@ fn test_shortscale_extra() {
         );
     }
 }
+
+#[test]
+fn test_shortscale_write() {
+    use std::fmt::Write;
+
+    struct Words(u64);
+
+    impl std::fmt::Display for Words {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            shortscale::shortscale_write(f, self.0)
+        }
+    }
+
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_write {}", num);
+        let mut buf = String::from("Hello ");
+        write!(&mut buf, "{}", Words(*num)).unwrap();
+        assert_eq!(buf, ["Hello ", *expected].concat());
+
+        println!("shortscale_io_write {}", num);
+        let mut out: Vec<u8> = b"Hello ".to_vec();
+        shortscale::shortscale_io_write(&mut out, *num).unwrap();
+        assert_eq!(out, ["Hello ", *expected].concat().as_bytes());
+    }
+}
+
+#[test]
+fn test_shortscale_io_write_error() {
+    let mut buf = [0u8; 10];
+    let mut out: &mut [u8] = &mut buf;
+    let err = shortscale::shortscale_io_write(&mut out, 420_000_999_015).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
+
+const TESTS_US: [(u64, &str); 8] = [
+    (0, "zero"),
+    (22, "twenty-two"),
+    (101, "one hundred one"),
+    (120, "one hundred twenty"),
+    (2_004, "two thousand four"),
+    (430_920, "four hundred thirty thousand nine hundred twenty"),
+    (
+        999_999_120,
+        "nine hundred ninety-nine million nine hundred ninety-nine thousand one hundred twenty",
+    ),
+    (1_999_999_999_999_999_999, "(big number)"),
+];
+
+#[test]
+fn test_num_words() {
+    for (num, expected) in TESTS.iter() {
+        println!("NumWords {}", num);
+        assert_eq!(shortscale::NumWords::new(*num).to_string(), *expected);
+    }
+    let us = shortscale::Options::new()
+        .style(shortscale::Style::US)
+        .hyphenate(true);
+    for (num, expected) in TESTS_US.iter() {
+        println!("NumWords US {}", num);
+        assert_eq!(
+            shortscale::NumWords::new(*num)
+                .style(shortscale::Style::US)
+                .hyphenate(true)
+                .to_string(),
+            *expected
+        );
+        assert_eq!(shortscale::shortscale_with(*num, &us), *expected);
+    }
+    assert_eq!(
+        format!("{}", shortscale::NumWords::new(121).hyphenate(true)),
+        "one hundred and twenty-one"
+    );
+}
+
+#[test]
+fn test_shortscale_padded() {
+    use shortscale::{shortscale_padded, Align, Error, NumWords};
+
+    assert_eq!(
+        shortscale_padded(42, 12, Align::Left).unwrap(),
+        "forty two   "
+    );
+    assert_eq!(
+        shortscale_padded(42, 12, Align::Right).unwrap(),
+        "   forty two"
+    );
+    assert_eq!(
+        shortscale_padded(42, 12, Align::Center).unwrap(),
+        " forty two  "
+    );
+    assert_eq!(
+        shortscale_padded(42, 9, Align::Center).unwrap(),
+        "forty two"
+    );
+    assert_eq!(
+        shortscale_padded(420, 12, Align::Left),
+        Err(Error::Width {
+            width: 12,
+            needed: 23
+        })
+    );
+
+    let words = NumWords::new(42);
+    assert_eq!(format!("{:12}|", words), "forty two   |");
+    assert_eq!(format!("{:>12}", words), "   forty two");
+    assert_eq!(format!("{:*^13}", words), "**forty two**");
+    assert_eq!(format!("{:.5}", words), "forty");
+    assert_eq!(format!("{:>4}", words), "forty two");
+    assert_eq!(format!("{:>width$}", words, width = 10), " forty two");
+}
+
+#[test]
+fn test_num_words_arith() {
+    use shortscale::{Error, NumWords};
+
+    let out_of_range = Error::OutOfRange {
+        min: 0,
+        max: 999_999_999_999_999_999,
+    };
+    let sum = NumWords::sum(&[400, 20, 1]).unwrap();
+    assert_eq!(sum.number(), 421);
+    assert_eq!(
+        sum.hyphenate(true).to_string(),
+        "four hundred and twenty-one"
+    );
+    assert_eq!(NumWords::sum(&[]).unwrap().to_string(), "zero");
+    assert_eq!(
+        NumWords::sum(&[999_999_999_999_999_998, 1])
+            .unwrap()
+            .number(),
+        999_999_999_999_999_999
+    );
+    assert_eq!(
+        NumWords::sum(&[999_999_999_999_999_999, 1]),
+        Err(out_of_range.clone())
+    );
+    assert_eq!(NumWords::sum(&[u64::MAX, 1]), Err(out_of_range.clone()));
+    assert_eq!(NumWords::product(&[]).unwrap().number(), 1);
+    assert_eq!(NumWords::product(&[6, 7]).unwrap().to_string(), "forty two");
+    assert_eq!(
+        NumWords::product(&[1_000_000_000, 1_000_000_000]),
+        Err(out_of_range.clone())
+    );
+
+    let percent = [
+        (3, 4, "seventy five percent"),
+        (0, 4, "zero percent"),
+        (1, 3, "thirty three percent"),
+        (2, 3, "sixty seven percent"),
+        (1, 200, "one percent"),
+        (5, 2, "two hundred and fifty percent"),
+    ];
+    for (part, whole, expected) in percent.iter() {
+        println!("percent {} {}", part, whole);
+        assert_eq!(NumWords::percent(*part, *whole).unwrap(), *expected);
+    }
+    assert_eq!(NumWords::percent(u64::MAX, 1), Err(out_of_range));
+
+    assert_eq!(NumWords::fraction(75, 100).unwrap(), "three quarters");
+    assert_eq!(NumWords::fraction(2, 6).unwrap(), "one third");
+    assert_eq!(NumWords::fraction(0, 6).unwrap(), "zero");
+    let zero_whole = Error::OutOfRange {
+        min: 1,
+        max: u64::MAX,
+    };
+    assert_eq!(NumWords::percent(1, 0), Err(zero_whole.clone()));
+    assert_eq!(NumWords::fraction(1, 0), Err(zero_whole));
+}
+
+#[test]
+fn test_shortscale_parse() {
+    for (num, expected) in TESTS
+        .iter()
+        .filter(|(num, _)| *num <= 999_999_999_999_999_999)
+    {
+        println!("shortscale_parse {}", num);
+        assert_eq!(shortscale::shortscale_parse(expected), Ok(*num));
+    }
+    for (num, expected) in TESTS_US
+        .iter()
+        .filter(|(num, _)| *num <= 999_999_999_999_999_999)
+    {
+        println!("shortscale_parse US {}", num);
+        assert_eq!(shortscale::shortscale_parse(expected), Ok(*num));
+    }
+    assert_eq!(
+        shortscale::shortscale_parse("  One Million,\ttwenty-TWO "),
+        Ok(1_000_022)
+    );
+}
+
+#[test]
+fn test_shortscale_slug() {
+    let us = shortscale::Options::new().style(shortscale::Style::US);
+    for (num, _) in TESTS
+        .iter()
+        .filter(|(num, _)| *num <= 999_999_999_999_999_999)
+    {
+        let slug = shortscale::shortscale_slug(*num);
+        println!("shortscale_slug {} {}", num, slug);
+        assert_eq!(
+            slug,
+            shortscale::shortscale_with(*num, &us).replace(' ', "_")
+        );
+        assert_eq!(shortscale::parse_slug(&slug), Ok(*num));
+    }
+    assert_eq!(
+        shortscale::shortscale_slug(420_000_999_015),
+        "four_hundred_twenty_billion_nine_hundred_ninety_nine_thousand_fifteen"
+    );
+    assert_eq!(
+        shortscale::shortscale_slug(u64::MAX).split('_').next(),
+        Some("eighteen")
+    );
+    for num in [
+        1_000_000_000_000_000_000,
+        1_000_000_000_000_000_001,
+        12_345_678_901_234_567_890,
+        u64::MAX,
+    ]
+    .iter()
+    {
+        let slug = shortscale::shortscale_slug(*num);
+        println!("shortscale_slug {} {}", num, slug);
+        assert_eq!(shortscale::parse_slug(&slug), Ok(*num));
+    }
+    assert_eq!(
+        shortscale::parse_slug("nineteen_quintillion"),
+        Err(shortscale::Error::OutOfRange {
+            min: 0,
+            max: u64::MAX
+        })
+    );
+    assert_eq!(
+        shortscale::parse_slug("quintillion"),
+        Err(shortscale::Error::UnexpectedWord {
+            word: "quintillion".to_string(),
+            offset: 0
+        })
+    );
+    assert_eq!(
+        shortscale::parse_slug("one_quintillion_two_zillion"),
+        Err(shortscale::Error::UnknownWord {
+            word: "zillion".to_string(),
+            offset: 20
+        })
+    );
+    assert_eq!(
+        shortscale::parse_slug("one_quintillion_two_quintillion"),
+        Err(shortscale::Error::UnexpectedWord {
+            word: "quintillion".to_string(),
+            offset: 20
+        })
+    );
+    assert_eq!(
+        shortscale::parse_slug("one_quintillion_quintillion"),
+        Err(shortscale::Error::UnexpectedWord {
+            word: "quintillion".to_string(),
+            offset: 16
+        })
+    );
+    assert_eq!(
+        shortscale::parse_slug("one_quintillion_zero"),
+        Err(shortscale::Error::UnexpectedWord {
+            word: "zero".to_string(),
+            offset: 16
+        })
+    );
+    assert_eq!(shortscale::parse_slug("four_hundred_and_twenty"), Ok(420));
+    assert_eq!(
+        shortscale::parse_slug("four hundred"),
+        Err(shortscale::Error::InvalidChar { ch: ' ', offset: 4 })
+    );
+    assert_eq!(
+        shortscale::parse_slug("four_fours"),
+        Err(shortscale::Error::UnknownWord {
+            word: "fours".to_string(),
+            offset: 5
+        })
+    );
+    assert_eq!(
+        shortscale::parse_slug(""),
+        Err(shortscale::Error::EmptyInput)
+    );
+}
+
+#[test]
+fn test_shortscale_parse_errors() {
+    use shortscale::{shortscale_parse, Error};
+
+    let unexpected = |word: &str, offset| {
+        Err(Error::UnexpectedWord {
+            word: word.to_string(),
+            offset,
+        })
+    };
+    assert_eq!(shortscale_parse(""), Err(Error::EmptyInput));
+    assert_eq!(shortscale_parse(" - "), Err(Error::EmptyInput));
+    assert_eq!(
+        shortscale_parse("one zillion"),
+        Err(Error::UnknownWord {
+            word: "zillion".to_string(),
+            offset: 4
+        })
+    );
+    assert_eq!(
+        shortscale_parse("(big number)"),
+        Err(Error::UnknownWord {
+            word: "(big".to_string(),
+            offset: 0
+        })
+    );
+    assert_eq!(shortscale_parse("zero zero"), unexpected("zero", 5));
+    assert_eq!(shortscale_parse("one zero"), unexpected("zero", 4));
+    assert_eq!(shortscale_parse("twenty twenty"), unexpected("twenty", 7));
+    assert_eq!(shortscale_parse("twenty ten"), unexpected("ten", 7));
+    assert_eq!(shortscale_parse("eleven hundred"), unexpected("hundred", 7));
+    assert_eq!(shortscale_parse("hundred"), unexpected("hundred", 0));
+    assert_eq!(shortscale_parse("thousand"), unexpected("thousand", 0));
+    assert_eq!(shortscale_parse("and one"), unexpected("and", 0));
+    assert_eq!(shortscale_parse("one and two"), unexpected("and", 4));
+    assert_eq!(
+        shortscale_parse("one thousand million"),
+        unexpected("million", 13)
+    );
+    assert_eq!(
+        shortscale_parse("one thousand one thousand"),
+        unexpected("thousand", 17)
+    );
+    assert_eq!(
+        shortscale_parse("one thousand and and one"),
+        unexpected("and", 17)
+    );
+    assert_eq!(
+        shortscale_parse("one hundred and"),
+        Err(Error::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn test_error() {
+    use shortscale::{Error, Style};
+
+    assert_eq!("gb".parse(), Ok(Style::GB));
+    assert_eq!("en-US".parse(), Ok(Style::US));
+    assert_eq!("en_us".parse(), Ok(Style::US));
+    assert_eq!(
+        "fr".parse::<Style>(),
+        Err(Error::UnsupportedLocale {
+            name: "fr".to_string()
+        })
+    );
+
+    let errors = [
+        (Error::EmptyInput, "empty input"),
+        (
+            Error::OutOfRange { min: 1, max: 3999 },
+            "number out of range 1 to 3999",
+        ),
+        (
+            Error::Width {
+                width: 20,
+                needed: 24,
+            },
+            "text needs 24 characters, more than the width 20",
+        ),
+        (
+            Error::UnsupportedLocale {
+                name: "fr".to_string(),
+            },
+            "unsupported locale \"fr\"",
+        ),
+    ];
+    for (error, expected) in errors.iter() {
+        println!("{}", error);
+        assert_eq!(error.to_string(), *expected);
+    }
+}
+
+#[test]
+fn test_shortscale_year() {
+    let tests = [
+        (0, "zero"),
+        (66, "sixty six"),
+        (100, "one hundred"),
+        (305, "three hundred and five"),
+        (476, "four hundred and seventy six"),
+        (800, "eight hundred"),
+        (999, "nine hundred and ninety nine"),
+        (1000, "one thousand"),
+        (1005, "one thousand and five"),
+        (1066, "ten sixty six"),
+        (1100, "eleven hundred"),
+        (1905, "nineteen oh five"),
+        (1984, "nineteen eighty four"),
+        (2000, "two thousand"),
+        (2007, "two thousand and seven"),
+        (2010, "twenty ten"),
+        (2024, "twenty twenty four"),
+        (2100, "twenty one hundred"),
+        (10_000, "ten thousand"),
+    ];
+    for (year, expected) in tests.iter() {
+        println!("shortscale_year {}", year);
+        assert_eq!(shortscale::shortscale_year(*year), *expected);
+    }
+}
+
+#[test]
+fn test_shortscale_digits() {
+    let tests = [
+        ("2024", 2, "twenty twenty four"),
+        ("2024", 1, "two oh two four"),
+        ("100", 1, "one double-oh"),
+        ("1000", 1, "one triple-oh"),
+        ("10000", 1, "one double-oh double-oh"),
+        ("100000", 1, "one triple-oh double-oh"),
+        (
+            "0800 555 1234",
+            3,
+            "oh eight double-oh, five hundred and fifty five, one thousand two hundred and thirty four",
+        ),
+        (
+            "07700-900123",
+            1,
+            "oh double-seven double-oh, nine double-oh one two three",
+        ),
+        ("1905", 2, "nineteen oh five"),
+        ("1234", 3, "one thousand two hundred and thirty four"),
+        ("12345", 2, "twelve three hundred and forty five"),
+        ("0800", 2, "oh eight double-oh"),
+        ("000000", 2, "triple-oh triple-oh"),
+        ("050017", 2, "oh five double-oh seventeen"),
+    ];
+    for (digits, group, expected) in tests.iter() {
+        println!("shortscale_digits {} {}", digits, group);
+        assert_eq!(
+            shortscale::shortscale_digits(digits, *group).unwrap(),
+            *expected
+        );
+    }
+    assert_eq!(
+        shortscale::shortscale_digits(" - ", 1),
+        Err(shortscale::Error::EmptyInput)
+    );
+    assert_eq!(
+        shortscale::shortscale_digits("+44 20", 2),
+        Err(shortscale::Error::InvalidChar { ch: '+', offset: 0 })
+    );
+    assert_eq!(
+        shortscale::shortscale_digits("12345678901234567890", 20),
+        Err(shortscale::Error::OutOfRange { min: 1, max: 18 })
+    );
+    assert_eq!(
+        shortscale::shortscale_digits("555", 0),
+        Err(shortscale::Error::OutOfRange { min: 1, max: 18 })
+    );
+}
+
+#[test]
+fn test_shortscale_grouped() {
+    let tests = [
+        (
+            8_005_551_234,
+            3,
+            ", ",
+            "eight hundred, five five five, one two three four",
+        ),
+        (
+            18_005_551_234,
+            3,
+            ", ",
+            "one eight oh, oh five five, five one two, three four",
+        ),
+        (1_020_304, 2, " - ", "one oh - two oh - three oh four"),
+        (
+            1_000_555,
+            4,
+            " <break/> ",
+            "one thousand <break/> five five five",
+        ),
+        (123, 1, ", ", "one, two, three"),
+        (123, 0, ", ", "one, two, three"),
+        (0, 3, ", ", "oh"),
+        (42, 3, ", ", "four two"),
+        (
+            10_000_000_000_000_000_000,
+            20,
+            ", ",
+            "one oh oh oh oh oh oh oh oh oh oh oh oh oh oh oh oh oh oh oh",
+        ),
+        (
+            u64::MAX,
+            20,
+            ", ",
+            "one eight four four six seven four four oh seven three seven oh nine five five one six one five",
+        ),
+    ];
+    for (num, group, sep, expected) in tests.iter() {
+        println!("shortscale_grouped {} {}", num, group);
+        assert_eq!(shortscale::shortscale_grouped(*num, *group, sep), *expected);
+    }
+}
+
+#[test]
+fn test_shortscale_ordinal() {
+    let tests = [
+        (0, "zeroth", "0th"),
+        (1, "first", "1st"),
+        (2, "second", "2nd"),
+        (3, "third", "3rd"),
+        (4, "fourth", "4th"),
+        (11, "eleventh", "11th"),
+        (12, "twelfth", "12th"),
+        (13, "thirteenth", "13th"),
+        (20, "twentieth", "20th"),
+        (21, "twenty first", "21st"),
+        (22, "twenty second", "22nd"),
+        (100, "one hundredth", "100th"),
+        (101, "one hundred and first", "101st"),
+        (111, "one hundred and eleventh", "111th"),
+        (113, "one hundred and thirteenth", "113th"),
+        (1_000_000, "one millionth", "1000000th"),
+        (
+            420_000_999_015,
+            "four hundred and twenty billion nine hundred and ninety nine thousand and fifteenth",
+            "420000999015th",
+        ),
+        (
+            1_999_999_999_999_999_999,
+            "(big number)",
+            "1999999999999999999th",
+        ),
+    ];
+    for (num, words, numeric) in tests.iter() {
+        println!("shortscale_ordinal {}", num);
+        assert_eq!(shortscale::shortscale_ordinal(*num), *words);
+        assert_eq!(shortscale::shortscale_ordinal_numeric(*num), *numeric);
+    }
+    let mut buf = String::from("the ");
+    shortscale::shortscale_ordinal_string_writer(&mut buf, 8);
+    assert_eq!(buf, "the eighth");
+
+    let us = shortscale::Options::new()
+        .style(shortscale::Style::US)
+        .hyphenate(true);
+    assert_eq!(
+        shortscale::shortscale_ordinal_with(121, &us),
+        "one hundred twenty-first"
+    );
+}
+
+#[test]
+fn test_shortscale_locale() {
+    use shortscale::locale::{EnGb, EnUs};
+
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_locale EnGb {}", num);
+        assert_eq!(shortscale::shortscale_locale(*num, &EnGb), *expected);
+    }
+    let hyphenate = shortscale::Options::new().hyphenate(true);
+    for (num, expected) in TESTS_US.iter() {
+        println!("shortscale_locale EnUs {}", num);
+        let mut buf = String::new();
+        shortscale::shortscale_locale_write(&mut buf, *num, &EnUs, &hyphenate).unwrap();
+        assert_eq!(buf, *expected);
+    }
+}
+
+#[test]
+#[allow(clippy::inconsistent_digit_grouping)] // lakh and crore
+fn test_shortscale_indian() {
+    let tests = [
+        (0, "zero"),
+        (999, "nine hundred and ninety nine"),
+        (1_000, "one thousand"),
+        (99_999, "ninety nine thousand nine hundred and ninety nine"),
+        (1_00_000, "one lakh"),
+        (1_00_005, "one lakh and five"),
+        (12_34_567, "twelve lakh thirty four thousand five hundred and sixty seven"),
+        (1_23_45_678, "one crore twenty three lakh forty five thousand six hundred and seventy eight"),
+        (1_00_00_00_000, "one arab"),
+        (1_00_00_00_00_000, "one kharab"),
+        (9_99_99_99_99_99_99_99_999, "nine shankh ninety nine padma ninety nine nil ninety nine kharab \
+            ninety nine arab ninety nine crore ninety nine lakh ninety nine thousand nine hundred and ninety nine"),
+        (1_999_999_999_999_999_999, "(big number)"),
+    ];
+    for (num, expected) in tests.iter() {
+        println!("shortscale_indian {}", num);
+        assert_eq!(shortscale::shortscale_indian(*num), *expected);
+    }
+}
+
+#[test]
+fn test_shortscale_ref() {
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_ref {}", num);
+        assert_eq!(&*shortscale::shortscale_ref(*num), *expected);
+    }
+
+    // nested refs do not share the buffer
+    let one = shortscale::shortscale_ref(1);
+    let two = shortscale::shortscale_ref(2);
+    assert_eq!(format!("{} {}", one, two), "one two");
+    drop(one);
+    assert_eq!(two.len(), 3);
+
+    let count = (0..1_000)
+        .filter(|num| shortscale::shortscale_ref(*num).contains("seven"))
+        .count();
+    assert_eq!(count, 271);
+}
+
+#[test]
+fn test_shortscale_const() {
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_const {}", num);
+        assert_eq!(shortscale::shortscale_const(*num).as_str(), *expected);
+    }
+
+    const WORDS: &str = shortscale::shortscale!(420_000_999_015);
+    assert_eq!(
+        WORDS,
+        "four hundred and twenty billion nine hundred and ninety nine thousand and fifteen"
+    );
+    assert_eq!(shortscale::shortscale!(0), "zero");
+    assert_eq!(shortscale::shortscale!(u64::MAX), "(big number)");
+}
+
+#[test]
+fn test_shortscale_len() {
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_len {}", num);
+        assert_eq!(shortscale::shortscale_len(*num), expected.len());
+    }
+    for num in (0..2_000_000).chain((0..u64::MAX).step_by(1_000_000_000_000_007)) {
+        let len = shortscale::shortscale_len(num);
+        assert_eq!(len, shortscale::shortscale(num).len());
+        assert!(len <= shortscale::MAX_LEN);
+    }
+    assert_eq!(
+        shortscale::shortscale_len(777_777_777_777_777_777),
+        shortscale::MAX_LEN
+    );
+}
+
+#[test]
+fn test_shortscale_tokens() {
+    use shortscale::Token;
+
+    for (num, expected) in TESTS.iter() {
+        println!("shortscale_tokens {}", num);
+        let words: Vec<&str> = shortscale::shortscale_tokens(*num)
+            .map(|token| token.as_str())
+            .collect();
+        assert_eq!(words.join(" "), *expected);
+    }
+
+    let us = shortscale::Options::new()
+        .style(shortscale::Style::US)
+        .hyphenate(true);
+    let tokens: Vec<Token> = shortscale::shortscale_tokens_with(1_121, &us).collect();
+    assert_eq!(
+        tokens,
+        [
+            Token::Unit("one"),
+            Token::Scale("thousand"),
+            Token::Unit("one"),
+            Token::Scale("hundred"),
+            Token::Unit("twenty"),
+            Token::Hyphen,
+            Token::Unit("one"),
+        ]
+    );
+    assert_eq!(
+        shortscale::shortscale_tokens(u64::MAX).collect::<Vec<Token>>(),
+        [Token::BigNumber]
+    );
+    assert_eq!(Token::BigNumber.as_str(), "(big number)");
+
+    // same words as shortscale_with, for both styles
+    for options in [shortscale::Options::new().hyphenate(true), us].iter() {
+        for (num, _) in TESTS.iter() {
+            let words: Vec<&str> = shortscale::shortscale_tokens_with(*num, options)
+                .map(|token| token.as_str())
+                .collect();
+            assert_eq!(
+                words.join(" ").replace(" - ", "-"),
+                shortscale::shortscale_with(*num, options)
+            );
+        }
+    }
+
+    // separator, conjunction, and case do not change the tokens
+    let custom = shortscale::Options::new()
+        .separator("_")
+        .conjunction("und")
+        .case(shortscale::Case::Title);
+    for (num, _) in TESTS.iter() {
+        assert!(shortscale::shortscale_tokens_with(*num, &custom)
+            .eq(shortscale::shortscale_tokens(*num)));
+    }
+}
+
+#[test]
+fn test_shortscale_ssml() {
+    use shortscale::{shortscale_ssml, Case, Options, SsmlOptions};
+
+    let tests = [
+        (0, "<speak>zero</speak>"),
+        (1_000, "<speak>one thousand</speak>"),
+        (
+            420_000_999_015,
+            "<speak>four hundred and twenty billion<break time=\"200ms\"/> \
+            nine hundred and ninety nine thousand<break time=\"200ms\"/> and fifteen</speak>",
+        ),
+        (
+            u64::MAX,
+            "<speak><say-as interpret-as=\"cardinal\">18446744073709551615</say-as></speak>",
+        ),
+    ];
+    for (num, expected) in tests.iter() {
+        println!("shortscale_ssml {}", num);
+        assert_eq!(shortscale_ssml(*num, &SsmlOptions::new()), *expected);
+    }
+
+    let ssml = SsmlOptions::new()
+        .pause_ms(0)
+        .speak(false)
+        .options(Options::new().hyphenate(true));
+    assert_eq!(shortscale_ssml(1_021, &ssml), "one thousand and twenty-one");
+
+    let ssml = SsmlOptions::new().speak(false).options(
+        Options::new()
+            .case(Case::Title)
+            .separator("_")
+            .conjunction("und")
+            .hyphenate(true),
+    );
+    assert_eq!(
+        shortscale_ssml(421_021, &ssml),
+        "Four_Hundred_und_Twenty-One_Thousand<break time=\"200ms\"/>_und_Twenty-One"
+    );
+    let ssml = SsmlOptions::new().options(Options::new().case(Case::Upper));
+    assert_eq!(
+        shortscale_ssml(2_020, &ssml),
+        "<speak>TWO THOUSAND<break time=\"200ms\"/> AND TWENTY</speak>"
+    );
+}
+
+#[test]
+fn test_shortscale_case() {
+    use shortscale::{shortscale_ordinal_with, shortscale_with, Case, NumWords, Options, Style};
+
+    let tests = [
+        (
+            Case::Lower,
+            "four hundred and twenty thousand and twenty-one",
+        ),
+        (
+            Case::Title,
+            "Four Hundred and Twenty Thousand and Twenty-One",
+        ),
+        (
+            Case::Upper,
+            "FOUR HUNDRED AND TWENTY THOUSAND AND TWENTY-ONE",
+        ),
+        (
+            Case::Sentence,
+            "Four hundred and twenty thousand and twenty-one",
+        ),
+    ];
+    for (case, expected) in tests.iter() {
+        println!("shortscale case {:?}", case);
+        let options = Options::new().hyphenate(true).case(*case);
+        assert_eq!(shortscale_with(420_021, &options), *expected);
+        assert_eq!(
+            NumWords::new(420_021)
+                .hyphenate(true)
+                .case(*case)
+                .to_string(),
+            *expected
+        );
+    }
+
+    let title = Options::new().case(Case::Title);
+    assert_eq!(shortscale_with(0, &title), "Zero");
+    assert_eq!(shortscale_with(u64::MAX, &title), "(Big Number)");
+    assert_eq!(
+        shortscale_with(101, &title.style(Style::US)),
+        "One Hundred One"
+    );
+    assert_eq!(
+        shortscale_ordinal_with(121, &title.hyphenate(true)),
+        "One Hundred and Twenty-First"
+    );
+    assert_eq!(
+        shortscale_ordinal_with(1_000, &Options::new().case(Case::Upper)),
+        "ONE THOUSANDTH"
+    );
+    assert_eq!(
+        shortscale_ordinal_with(12, &Options::new().case(Case::Sentence)),
+        "Twelfth"
+    );
+}
+
+#[test]
+fn test_shortscale_zero() {
+    use shortscale::{
+        shortscale_locale, shortscale_locale_write, shortscale_ordinal_with, shortscale_parse,
+        shortscale_tokens_with, shortscale_with, Case, NumWords, Options, Token, ZeroWord,
+    };
+
+    let tests = [
+        (ZeroWord::Zero, "zero"),
+        (ZeroWord::Nought, "nought"),
+        (ZeroWord::Nil, "nil"),
+        (ZeroWord::Oh, "oh"),
+    ];
+    for (zero, expected) in tests.iter() {
+        println!("shortscale zero {:?}", zero);
+        let options = Options::new().zero(*zero);
+        assert_eq!(shortscale_with(0, &options), *expected);
+        assert_eq!(NumWords::new(0).zero(*zero).to_string(), *expected);
+        assert_eq!(shortscale_with(10, &options), "ten");
+        assert_eq!(shortscale_parse(expected), Ok(0));
+        assert_eq!(
+            shortscale_tokens_with(0, &options).collect::<Vec<Token>>(),
+            [Token::Unit(expected)]
+        );
+        assert_eq!(shortscale_ordinal_with(0, &options), "zeroth");
+
+        let mut s = String::new();
+        shortscale_locale_write(&mut s, 0, &shortscale::locale::EnIn, &options).unwrap();
+        assert_eq!(s, *expected);
+    }
+
+    let options = Options::new().zero(ZeroWord::Nil).case(Case::Upper);
+    assert_eq!(shortscale_with(0, &options), "NIL");
+    assert_eq!(shortscale_locale(0, &shortscale::locale::EnUs), "zero");
+}
+
+#[test]
+fn test_shortscale_separator() {
+    use shortscale::{
+        shortscale_approx_with, shortscale_ordinal_with, shortscale_tokens_with, shortscale_with,
+        ApproxOptions, Case, NumWords, Options, Style, ToWords,
+    };
+
+    let tests = [
+        (Options::new().separator("-"), "four-hundred-and-twenty-one"),
+        (
+            Options::new().separator("_").hyphenate(true),
+            "four_hundred_and_twenty-one",
+        ),
+        (Options::new().separator(""), "fourhundredandtwentyone"),
+        (Options::new().conjunction("&"), "four hundred & twenty one"),
+        (Options::new().conjunction(""), "four hundred twenty one"),
+        (
+            Options::new().conjunction("und").style(Style::US),
+            "four hundred twenty one",
+        ),
+        (
+            Options::new().separator("-").case(Case::Title),
+            "Four-Hundred-and-Twenty-One",
+        ),
+        (
+            Options::new().conjunction("plus").case(Case::Title),
+            "Four Hundred plus Twenty One",
+        ),
+    ];
+    for (options, expected) in tests.iter() {
+        println!("shortscale separator {:?}", options);
+        assert_eq!(shortscale_with(421, options), *expected);
+    }
+
+    let words = NumWords::new(1_000_001).separator("-").conjunction("");
+    assert_eq!(words.to_string(), "one-million-one");
+
+    let options = Options::new().separator("-");
+    assert_eq!(
+        shortscale_ordinal_with(421, &options),
+        "four-hundred-and-twenty-first"
+    );
+    let options = Options::new().conjunction("").case(Case::Upper);
+    assert_eq!(
+        shortscale_ordinal_with(421, &options),
+        "FOUR HUNDRED TWENTY FIRST"
+    );
+    assert_eq!(
+        (-5_i32).to_words_with(&Options::new().separator("-")),
+        "minus-five"
+    );
+    assert_eq!(
+        shortscale_tokens_with(421, &Options::new().separator("-").conjunction("&")).count(),
+        5
+    );
+    let approx = ApproxOptions::new().options(Options::new().separator("-"));
+    assert_eq!(
+        shortscale_approx_with(421, &approx),
+        "about-four-hundred-and-twenty"
+    );
+}
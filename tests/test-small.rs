@@ -0,0 +1,23 @@
+#![cfg(feature = "smallstring")]
+
+use shortscale::{shortscale, shortscale_small, MAX_LEN};
+
+#[test]
+fn test_shortscale_small() {
+    for num in [
+        0,
+        1,
+        420,
+        777_777_777_777_777_777,
+        999_999_999_999_999_999,
+        u64::MAX,
+    ]
+    .iter()
+    {
+        println!("shortscale_small {}", num);
+        let words = shortscale_small(*num);
+        assert_eq!(words.as_str(), shortscale(*num));
+        assert!(!words.spilled());
+    }
+    assert_eq!(shortscale_small(777_777_777_777_777_777).len(), MAX_LEN);
+}
@@ -0,0 +1,53 @@
+use shortscale::{
+    pronunciation, shortscale_annotate, shortscale_stressed, shortscale_tokens_with, Options,
+    Token, ZeroWord,
+};
+
+#[test]
+fn test_pronunciation() {
+    let zero_words = [ZeroWord::Nought, ZeroWord::Nil, ZeroWord::Oh];
+    let nums = (0..1_000).chain((3..18).map(|power| 10_u64.pow(power)));
+    let tokens = nums
+        .flat_map(|num| shortscale_tokens_with(num, &Options::new()))
+        .chain(zero_words.iter().map(|zero| Token::Unit(zero.as_str())));
+    for token in tokens {
+        let word = token.as_str();
+        let p = pronunciation(word).unwrap_or_else(|| panic!("no pronunciation for {}", word));
+        assert_eq!(p.word, word);
+        assert_eq!(p.syllables.concat(), word);
+        assert!(p.stress < p.syllables.len());
+        assert!(!p.ipa.is_empty());
+    }
+    assert_eq!(pronunciation("Hundred"), None);
+}
+
+#[test]
+fn test_shortscale_stressed() {
+    let tests = [
+        (0, "ZE-ro"),
+        (11, "e-LEV-en"),
+        (17, "sev-en-TEEN"),
+        (420, "four HUN-dred and TWEN-ty"),
+        (2_000_013, "two MIL-lion and thir-TEEN"),
+        (1_000_000_000_000_000, "one quad-RIL-lion"),
+        (u64::MAX, "(big number)"),
+    ];
+    for (num, expected) in tests.iter() {
+        println!("stressed {}", num);
+        assert_eq!(shortscale_stressed(*num), *expected);
+    }
+}
+
+#[test]
+fn test_shortscale_annotate() {
+    let options = Options::new().hyphenate(true);
+    let marked = shortscale_annotate(121, &options, |s, token| match token {
+        Token::Scale(word) => {
+            s.push('[');
+            s.push_str(word);
+            s.push(']');
+        }
+        _ => s.push_str(token.as_str()),
+    });
+    assert_eq!(marked, "one [hundred] and twenty-one");
+}
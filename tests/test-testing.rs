@@ -0,0 +1,56 @@
+#![cfg(feature = "testing")]
+
+use shortscale::testing::{check, check_all, implementations, random_samples, samples, Mismatch};
+
+// all numbers below 1_000_000 take about a minute in a debug build
+#[test]
+#[ignore]
+fn test_testing_samples_exhaustive() {
+    assert_eq!(check_all(samples()), Ok(samples().count()));
+}
+
+// a bounded part of the numbers below 1_000_000 for CI
+#[test]
+fn test_testing_samples_bounded() {
+    let small = samples().take(10_000);
+    assert_eq!(check_all(small), Ok(10_000));
+    let spread = samples().take(1_000_000).step_by(101);
+    assert_eq!(check_all(spread), Ok(9_901));
+}
+
+#[test]
+fn test_testing_samples() {
+    let structured = samples().skip(1_000_000);
+    assert_eq!(check_all(structured), Ok(samples().count() - 1_000_000));
+    assert!(samples().any(|num| num == 717_000_000_000));
+    assert!(samples().any(|num| num == 111_111_111_111_111_111));
+}
+
+#[test]
+fn test_testing_random_samples() {
+    let nums: Vec<u64> = random_samples(42, 100_000).collect();
+    assert!(nums == random_samples(42, 100_000).collect::<Vec<u64>>());
+    assert!(nums != random_samples(43, 100_000).collect::<Vec<u64>>());
+    assert!(nums.iter().any(|num| *num < 1_000));
+    assert!(nums.iter().any(|num| *num > 999_999_999_999_999_999));
+    assert_eq!(check_all(nums), Ok(100_000));
+}
+
+#[test]
+fn test_testing_check() {
+    assert!(implementations().len() >= 11);
+    let names: Vec<&str> = implementations().iter().map(|i| i.name).collect();
+    assert!(names.contains(&"shortscale_locale EnUs"));
+    assert!(names.contains(&"shortscale_locale EnIn"));
+    assert_eq!(check(420), Ok(()));
+    let mismatch = Mismatch {
+        name: "test",
+        num: 40,
+        expected: "forty".to_string(),
+        actual: "fourty".to_string(),
+    };
+    assert_eq!(
+        mismatch.to_string(),
+        r#"test for 40 returned "fourty", expected "forty""#
+    );
+}
@@ -0,0 +1,55 @@
+use shortscale::{shortscale_clock, shortscale_clock_with, shortscale_duration, ClockStyle, Error};
+
+#[test]
+fn test_shortscale_duration() {
+    let tests = [
+        (0, "zero seconds"),
+        (1, "one second"),
+        (60, "one minute"),
+        (61, "one minute and one second"),
+        (3_600, "one hour"),
+        (8_109, "two hours, fifteen minutes and nine seconds"),
+        (86_400, "one day"),
+        (90_061, "one day, one hour, one minute and one second"),
+        (172_800 + 59, "two days and fifty nine seconds"),
+    ];
+    for (secs, expected) in tests.iter() {
+        println!("duration {}", secs);
+        assert_eq!(shortscale_duration(*secs), *expected);
+    }
+}
+
+#[test]
+fn test_shortscale_clock() {
+    let tests = [
+        (0, 0, "twelve o'clock", "midnight"),
+        (12, 0, "twelve o'clock", "noon"),
+        (15, 0, "three o'clock", "three o'clock"),
+        (15, 1, "three oh one", "one minute past three"),
+        (15, 5, "three oh five", "five past three"),
+        (15, 15, "three fifteen", "quarter past three"),
+        (15, 22, "three twenty two", "twenty two minutes past three"),
+        (15, 30, "three thirty", "half past three"),
+        (15, 35, "three thirty five", "twenty five to four"),
+        (15, 45, "three forty five", "quarter to four"),
+        (15, 59, "three fifty nine", "one minute to four"),
+        (11, 50, "eleven fifty", "ten to twelve"),
+        (23, 45, "eleven forty five", "quarter to twelve"),
+    ];
+    for (hour, minute, digital, phrase) in tests.iter() {
+        println!("clock {}:{:02}", hour, minute);
+        assert_eq!(shortscale_clock(*hour, *minute).unwrap(), *digital);
+        assert_eq!(
+            shortscale_clock_with(*hour, *minute, ClockStyle::Phrase).unwrap(),
+            *phrase
+        );
+    }
+    assert_eq!(
+        shortscale_clock(24, 0),
+        Err(Error::OutOfRange { min: 0, max: 23 })
+    );
+    assert_eq!(
+        shortscale_clock(0, 60),
+        Err(Error::OutOfRange { min: 0, max: 59 })
+    );
+}
@@ -0,0 +1,80 @@
+use shortscale::{
+    shortscale, shortscale_bigint_with, shortscale_ordinal, shortscale_ordinal_with,
+    shortscale_with, Case, Options, Style, ToWords, ZeroWord,
+};
+
+#[test]
+fn test_to_words() {
+    assert_eq!(0_u8.to_words(), "zero");
+    assert_eq!(255_u8.to_words(), "two hundred and fifty five");
+    assert_eq!(42_u16.to_words(), "forty two");
+    assert_eq!(42_u32.to_words(), "forty two");
+    assert_eq!(42_usize.to_words(), "forty two");
+    assert_eq!((-5_i8).to_words(), "minus five");
+    assert_eq!(i8::MIN.to_words(), "minus one hundred and twenty eight");
+    assert_eq!((-5_i64).to_words(), "minus five");
+    assert_eq!(5_isize.to_words(), "five");
+    assert_eq!(
+        u64::MAX.to_words(),
+        "eighteen quintillion four hundred and forty six quadrillion \
+        seven hundred and forty four trillion seventy three billion \
+        seven hundred and nine million five hundred and fifty one thousand \
+        six hundred and fifteen"
+    );
+
+    for num in [
+        1,
+        21,
+        101,
+        1_000_001,
+        420_000_999_015,
+        999_999_999_999_999_999,
+    ]
+    .iter()
+    {
+        println!("to_words {}", num);
+        assert_eq!(num.to_words(), shortscale(*num));
+        assert_eq!((*num as i128).to_words(), shortscale(*num));
+        assert_eq!(
+            (-(*num as i64)).to_words(),
+            format!("minus {}", shortscale(*num))
+        );
+    }
+}
+
+#[test]
+fn test_to_words_with() {
+    let options = Options::new()
+        .style(Style::US)
+        .hyphenate(true)
+        .case(Case::Sentence);
+    assert_eq!(
+        420_000_999_015_u64.to_words_with(&options),
+        shortscale_with(420_000_999_015, &options)
+    );
+    assert_eq!((-21_i32).to_words_with(&options), "Minus twenty-one");
+    assert_eq!(
+        u128::MAX.to_words_with(&options),
+        shortscale_bigint_with(&u128::MAX.to_string(), &options).unwrap()
+    );
+    let zero = Options::new().zero(ZeroWord::Nil);
+    assert_eq!(0_i32.to_words_with(&zero), "nil");
+}
+
+#[test]
+fn test_to_ordinal_words() {
+    for num in [0, 1, 22, 100, 113, 1_000_000, 420_000_999_015].iter() {
+        println!("to_ordinal_words {}", num);
+        assert_eq!(num.to_ordinal_words(), shortscale_ordinal(*num));
+    }
+    let options = Options::new().case(Case::Title).hyphenate(true);
+    assert_eq!(
+        121_u8.to_ordinal_words_with(&options),
+        shortscale_ordinal_with(121, &options)
+    );
+    assert_eq!((-1_i16).to_ordinal_words(), "minus first");
+    assert_eq!(
+        1_000_000_000_000_000_000_u128.to_ordinal_words(),
+        "one quintillionth"
+    );
+}
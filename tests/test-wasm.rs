@@ -0,0 +1,14 @@
+#![cfg(feature = "wasm")]
+
+use shortscale::wasm::{ordinal_js, parse_js, shortscale_js};
+
+// error paths create JavaScript values, which requires a wasm32 target
+#[test]
+fn test_wasm() {
+    assert_eq!(
+        shortscale_js(420).ok(),
+        Some("four hundred and twenty".to_string())
+    );
+    assert_eq!(parse_js("four hundred").ok(), Some(400));
+    assert_eq!(ordinal_js(22).ok(), Some("twenty second".to_string()));
+}